@@ -0,0 +1,7 @@
+-- Create block_cursors table (last fully processed block per watched contract)
+CREATE TABLE IF NOT EXISTS block_cursors (
+    contract_address VARCHAR(42) PRIMARY KEY,
+    contract_name VARCHAR(50) NOT NULL,
+    last_block BIGINT NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
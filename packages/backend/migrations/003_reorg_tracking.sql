@@ -0,0 +1,13 @@
+-- Create processed_blocks table (block hashes seen by the listener, for reorg detection)
+CREATE TABLE IF NOT EXISTS processed_blocks (
+    block_number BIGINT PRIMARY KEY,
+    block_hash VARCHAR(66) NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+-- Track which block created and resolved each challenge so orphaned rows can be rolled back
+ALTER TABLE challenges ADD COLUMN IF NOT EXISTS block_number BIGINT;
+ALTER TABLE challenges ADD COLUMN IF NOT EXISTS resolved_block BIGINT;
+
+CREATE INDEX idx_challenges_block ON challenges(block_number);
+CREATE INDEX idx_challenges_resolved_block ON challenges(resolved_block);
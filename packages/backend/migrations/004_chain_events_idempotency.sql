@@ -0,0 +1,7 @@
+-- Identify each log by its position in the chain so replays become upserts
+ALTER TABLE chain_events ADD COLUMN IF NOT EXISTS log_index BIGINT;
+ALTER TABLE chain_events ADD COLUMN IF NOT EXISTS block_hash VARCHAR(66);
+
+-- Rows recorded without a log index (NULL) never conflict with each other
+ALTER TABLE chain_events
+    ADD CONSTRAINT chain_events_tx_log_unique UNIQUE (transaction_hash, log_index);
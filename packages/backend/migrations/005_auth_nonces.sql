@@ -0,0 +1,8 @@
+-- Create auth_nonces table (single-use nonces for Sign-In with Ethereum)
+CREATE TABLE IF NOT EXISTS auth_nonces (
+    nonce VARCHAR(64) PRIMARY KEY,
+    expires_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_auth_nonces_expires_at ON auth_nonces(expires_at);
@@ -0,0 +1,24 @@
+-- Create relayer_transactions table (privileged calls submitted by the backend signer)
+CREATE TABLE IF NOT EXISTS relayer_transactions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    kind VARCHAR(50) NOT NULL, -- e.g. ModerationGame.finalizeDispute
+    to_address VARCHAR(42) NOT NULL,
+    calldata TEXT NOT NULL,
+    dedupe_key VARCHAR(100) UNIQUE,
+    status VARCHAR(20) NOT NULL DEFAULT 'queued', -- queued, sending, submitted, confirmed, failed
+    nonce BIGINT,
+    gas_limit BIGINT,
+    tx_hash VARCHAR(66),
+    block_number BIGINT,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    submitted_at TIMESTAMP WITH TIME ZONE,
+    confirmed_at TIMESTAMP WITH TIME ZONE
+);
+
+CREATE INDEX idx_relayer_transactions_status ON relayer_transactions(status, created_at);
+
+CREATE TRIGGER update_relayer_transactions_updated_at BEFORE UPDATE ON relayer_transactions
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
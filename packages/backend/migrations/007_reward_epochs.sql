@@ -0,0 +1,11 @@
+-- Create reward_epochs table (StakingRewards epochs finalized by checkpoint)
+CREATE TABLE IF NOT EXISTS reward_epochs (
+    epoch_id BIGINT PRIMARY KEY,
+    total_rewards NUMERIC(78, 0) NOT NULL,
+    total_shares NUMERIC(78, 0) NOT NULL,
+    transaction_hash VARCHAR(66) NOT NULL,
+    block_number BIGINT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_reward_epochs_block ON reward_epochs(block_number);
@@ -0,0 +1,27 @@
+-- Create disputes table (ModerationGame disputes tracked from DisputeInitialized)
+CREATE TABLE IF NOT EXISTS disputes (
+    dispute_id BIGINT PRIMARY KEY, -- on-chain dispute ID
+    content_id BIGINT NOT NULL, -- on-chain content ID
+    challenger_address VARCHAR(42) NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'active', -- active, resolved
+    commit_deadline TIMESTAMP WITH TIME ZONE NOT NULL,
+    reveal_deadline TIMESTAMP WITH TIME ZONE NOT NULL,
+    guilty BOOLEAN,
+    guilty_votes BIGINT,
+    not_guilty_votes BIGINT,
+    block_number BIGINT NOT NULL,
+    transaction_hash VARCHAR(66) NOT NULL,
+    resolved_block BIGINT,
+    resolved_transaction_hash VARCHAR(66),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    resolved_at TIMESTAMP WITH TIME ZONE
+);
+
+CREATE INDEX idx_disputes_content ON disputes(content_id);
+CREATE INDEX idx_disputes_status_deadline ON disputes(status, reveal_deadline);
+CREATE INDEX idx_disputes_block ON disputes(block_number);
+CREATE INDEX idx_disputes_resolved_block ON disputes(resolved_block);
+
+CREATE TRIGGER update_disputes_updated_at BEFORE UPDATE ON disputes
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
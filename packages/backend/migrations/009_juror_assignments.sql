@@ -0,0 +1,22 @@
+-- Create juror_assignments table (jurors selected for each dispute and their vote progress)
+CREATE TABLE IF NOT EXISTS juror_assignments (
+    dispute_id BIGINT NOT NULL, -- on-chain dispute ID
+    juror_address VARCHAR(42) NOT NULL,
+    committed BOOLEAN NOT NULL DEFAULT FALSE,
+    revealed BOOLEAN NOT NULL DEFAULT FALSE,
+    vote BOOLEAN, -- true = guilty, set once revealed
+    block_number BIGINT NOT NULL,
+    committed_block BIGINT,
+    revealed_block BIGINT,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (dispute_id, juror_address)
+);
+
+CREATE INDEX idx_juror_assignments_juror ON juror_assignments(juror_address);
+CREATE INDEX idx_juror_assignments_block ON juror_assignments(block_number);
+CREATE INDEX idx_juror_assignments_committed_block ON juror_assignments(committed_block);
+CREATE INDEX idx_juror_assignments_revealed_block ON juror_assignments(revealed_block);
+
+CREATE TRIGGER update_juror_assignments_updated_at BEFORE UPDATE ON juror_assignments
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
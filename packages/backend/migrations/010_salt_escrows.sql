@@ -0,0 +1,14 @@
+-- Create salt_escrows table (encrypted commit-reveal vote and salt held for jurors)
+CREATE TABLE IF NOT EXISTS salt_escrows (
+    dispute_id BIGINT NOT NULL, -- on-chain dispute ID
+    juror_address VARCHAR(42) NOT NULL,
+    commitment VARCHAR(66) NOT NULL,
+    ciphertext TEXT NOT NULL, -- AES-256-GCM over vote || salt, hex encoded
+    nonce VARCHAR(24) NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (dispute_id, juror_address)
+);
+
+CREATE TRIGGER update_salt_escrows_updated_at BEFORE UPDATE ON salt_escrows
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
@@ -0,0 +1,2 @@
+-- Record the slashed bond from ChallengeResolved on the resolved challenge
+ALTER TABLE challenges ADD COLUMN IF NOT EXISTS slashed_amount NUMERIC(78, 0);
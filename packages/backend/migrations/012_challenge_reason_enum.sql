@@ -0,0 +1,21 @@
+-- Store challenge reasons as a typed enum mirroring ContentRegistry.ChallengeReason
+CREATE TYPE challenge_reason AS ENUM (
+    'spam',
+    'harassment',
+    'misinformation',
+    'illegal_content',
+    'other'
+);
+
+-- Existing rows hold the contract's uint8 value as text
+ALTER TABLE challenges ALTER COLUMN reason TYPE challenge_reason USING (
+    CASE reason
+        WHEN '0' THEN 'spam'
+        WHEN '1' THEN 'harassment'
+        WHEN '2' THEN 'misinformation'
+        WHEN '3' THEN 'illegal_content'
+        ELSE 'other'
+    END
+)::challenge_reason;
+
+CREATE INDEX idx_challenges_reason ON challenges(reason);
@@ -0,0 +1,9 @@
+-- Drafts have no on-chain ID until their ContentPublished event is seen
+ALTER TABLE contents ALTER COLUMN content_id DROP NOT NULL;
+
+-- Drafts were previously inserted with a placeholder ID of 0
+UPDATE contents SET content_id = NULL WHERE content_id = 0;
+
+-- Drafts are bound to ContentPublished by hash
+CREATE INDEX idx_contents_hash ON contents(content_hash);
+CREATE INDEX idx_contents_drafts ON contents(created_at) WHERE content_id IS NULL;
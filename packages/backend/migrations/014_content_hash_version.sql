@@ -0,0 +1,3 @@
+-- Version of the canonical encoding content_hash was computed with
+-- 1 = keccak256(title || body), 2 = EIP-712 typed data (see src/hashing.rs)
+ALTER TABLE contents ADD COLUMN IF NOT EXISTS hash_version SMALLINT NOT NULL DEFAULT 1;
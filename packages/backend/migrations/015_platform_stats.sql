@@ -0,0 +1,15 @@
+-- Cached /api/stats rollup, rebuilt periodically by the stats worker
+CREATE TABLE IF NOT EXISTS platform_stats (
+    time_window VARCHAR(10) NOT NULL, -- 24h, 7d, 30d, all
+    is_total BOOLEAN NOT NULL, -- platform-wide row; otherwise one row per community
+    community_id VARCHAR(100),
+    total_contents BIGINT NOT NULL,
+    total_challenges BIGINT NOT NULL,
+    total_resolved BIGINT NOT NULL,
+    total_guilty BIGINT NOT NULL,
+    total_bonded NUMERIC(78, 0) NOT NULL,
+    active_stakers BIGINT NOT NULL,
+    refreshed_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_platform_stats_window ON platform_stats(time_window);
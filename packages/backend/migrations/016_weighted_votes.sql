@@ -0,0 +1,6 @@
+-- Reputation multiplier at vote time, in percent (100 = 1.0x)
+ALTER TABLE votes ADD COLUMN IF NOT EXISTS weight INTEGER NOT NULL DEFAULT 100;
+
+-- Votes with any other type were never counted
+DELETE FROM votes WHERE vote_type NOT IN ('upvote', 'downvote');
+ALTER TABLE votes ADD CONSTRAINT votes_vote_type_check CHECK (vote_type IN ('upvote', 'downvote'));
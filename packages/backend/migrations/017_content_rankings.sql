@@ -0,0 +1,12 @@
+-- Feed ranking scores, recomputed periodically by the ranking worker
+CREATE TABLE IF NOT EXISTS content_rankings (
+    content_id UUID PRIMARY KEY REFERENCES contents(id) ON DELETE CASCADE,
+    score BIGINT NOT NULL, -- weighted upvotes minus downvotes, in hundredths of a vote
+    hot DOUBLE PRECISION NOT NULL,
+    rising DOUBLE PRECISION, -- only set for posts younger than a day
+    controversial DOUBLE PRECISION NOT NULL,
+    computed_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_content_rankings_hot ON content_rankings(hot DESC);
+CREATE INDEX idx_content_rankings_score ON content_rankings(score DESC);
@@ -0,0 +1,7 @@
+-- Full-text search; titles rank above bodies
+ALTER TABLE contents ADD COLUMN IF NOT EXISTS search_vector TSVECTOR
+    GENERATED ALWAYS AS (
+        setweight(to_tsvector('english', title), 'A') || setweight(to_tsvector('english', body), 'B')
+    ) STORED;
+
+CREATE INDEX idx_contents_search ON contents USING GIN (search_vector);
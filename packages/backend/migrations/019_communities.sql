@@ -0,0 +1,35 @@
+-- Create communities table; contents.community_id holds the slug
+CREATE TABLE IF NOT EXISTS communities (
+    slug VARCHAR(100) PRIMARY KEY,
+    name VARCHAR(100) NOT NULL,
+    description TEXT,
+    rules TEXT[] NOT NULL DEFAULT '{}',
+    icon_url TEXT,
+    min_stake NUMERIC(78, 0) NOT NULL DEFAULT 0, -- total stake required to post, in wei
+    allowed_content_types TEXT[] NOT NULL DEFAULT '{post,comment}',
+    creator_address VARCHAR(42) NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE TRIGGER update_communities_updated_at BEFORE UPDATE ON communities
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TABLE IF NOT EXISTS community_members (
+    community_slug VARCHAR(100) NOT NULL REFERENCES communities(slug) ON DELETE CASCADE,
+    member_address VARCHAR(42) NOT NULL,
+    joined_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (community_slug, member_address)
+);
+
+CREATE INDEX idx_community_members_member ON community_members(member_address);
+
+-- Adopt communities that already have content, owned by the zero address
+INSERT INTO communities (slug, name, creator_address)
+SELECT DISTINCT community_id, community_id, '0x0000000000000000000000000000000000000000'
+FROM contents
+WHERE community_id IS NOT NULL
+ON CONFLICT (slug) DO NOTHING;
+
+ALTER TABLE contents
+    ADD CONSTRAINT contents_community_fk FOREIGN KEY (community_id) REFERENCES communities(slug);
@@ -0,0 +1,10 @@
+-- Keep a relayer transaction's nonce across retries and record the fees of
+-- its latest broadcast, so stuck transactions are replaced instead of resent
+ALTER TABLE relayer_transactions
+    ADD COLUMN max_fee_per_gas BIGINT,
+    ADD COLUMN max_priority_fee_per_gas BIGINT,
+    -- Earlier broadcasts with the same nonce; any of them may still be mined
+    ADD COLUMN replaced_tx_hashes TEXT[] NOT NULL DEFAULT '{}';
+
+CREATE INDEX idx_relayer_transactions_nonce ON relayer_transactions(nonce)
+    WHERE nonce IS NOT NULL;
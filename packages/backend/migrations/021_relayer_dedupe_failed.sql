@@ -0,0 +1,6 @@
+-- A failed call must not block its dedupe key forever: only unfinished and
+-- confirmed transactions keep the key, so workers can queue the call again
+ALTER TABLE relayer_transactions DROP CONSTRAINT relayer_transactions_dedupe_key_key;
+
+CREATE UNIQUE INDEX idx_relayer_transactions_dedupe_key ON relayer_transactions(dedupe_key)
+    WHERE status <> 'failed';
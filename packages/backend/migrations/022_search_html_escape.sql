@@ -0,0 +1,6 @@
+-- Search highlights are returned as HTML with <mark> tags, so user text must be
+-- escaped before ts_headline adds them
+CREATE OR REPLACE FUNCTION html_escape(value TEXT) RETURNS TEXT AS $$
+    SELECT replace(replace(replace(replace(replace(value,
+        '&', '&amp;'), '<', '&lt;'), '>', '&gt;'), '"', '&quot;'), '''', '&#39;')
+$$ LANGUAGE SQL IMMUTABLE STRICT;
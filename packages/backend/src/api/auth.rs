@@ -0,0 +1,260 @@
+use alloy::primitives::{Address, Signature};
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use rand::{distr::Alphanumeric, Rng};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::{middleware::auth::issue_token, AppState};
+
+const NONCE_LENGTH: usize = 17;
+const NONCE_TTL_MINUTES: i64 = 10;
+
+#[derive(Debug, Serialize)]
+pub struct NonceResponse {
+    pub nonce: String,
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VerifyRequest {
+    pub message: String,
+    pub signature: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct VerifyResponse {
+    pub token: String,
+    pub address: String,
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+}
+
+pub async fn get_nonce(
+    State(state): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, StatusCode> {
+    let nonce: String = rand::rng()
+        .sample_iter(&Alphanumeric)
+        .take(NONCE_LENGTH)
+        .map(char::from)
+        .collect();
+    let expires_at = chrono::Utc::now() + chrono::Duration::minutes(NONCE_TTL_MINUTES);
+
+    state
+        .db
+        .create_auth_nonce(&nonce, expires_at)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(NonceResponse { nonce, expires_at }))
+}
+
+pub async fn verify(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<VerifyRequest>,
+) -> Result<impl IntoResponse, StatusCode> {
+    let message = SiweMessage::parse(&req.message).ok_or(StatusCode::BAD_REQUEST)?;
+    if !message.is_valid(
+        &state.config.siwe_domain,
+        state.config.chain_id,
+        chrono::Utc::now(),
+    ) {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
+    // EIP-191 personal_sign over the exact message text
+    let signature = Signature::from_str(&req.signature).map_err(|_| StatusCode::BAD_REQUEST)?;
+    let signer = signature
+        .recover_address_from_msg(req.message.as_bytes())
+        .map_err(|_| StatusCode::UNAUTHORIZED)?;
+    if signer != message.address {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
+    // Nonces are single use, so a captured message cannot be replayed
+    let consumed = state
+        .db
+        .consume_auth_nonce(&message.nonce)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    if !consumed {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
+    let (token, expires_at) =
+        issue_token(&state.config, signer).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(VerifyResponse {
+        token,
+        address: format!("{:?}", signer),
+        expires_at,
+    }))
+}
+
+/// The EIP-4361 fields the backend checks before issuing a session
+struct SiweMessage {
+    domain: String,
+    address: Address,
+    chain_id: u64,
+    nonce: String,
+    expiration_time: Option<chrono::DateTime<chrono::Utc>>,
+    not_before: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl SiweMessage {
+    fn parse(message: &str) -> Option<Self> {
+        let mut lines = message.lines();
+
+        let domain = lines
+            .next()?
+            .strip_suffix(" wants you to sign in with your Ethereum account:")?
+            .to_string();
+        let address = Address::from_str(lines.next()?.trim()).ok()?;
+
+        let mut chain_id = None;
+        let mut nonce = None;
+        let mut expiration_time = None;
+        let mut not_before = None;
+
+        for line in lines {
+            if let Some(value) = line.strip_prefix("Chain ID: ") {
+                chain_id = Some(value.trim().parse().ok()?);
+            } else if let Some(value) = line.strip_prefix("Nonce: ") {
+                nonce = Some(value.trim().to_string());
+            } else if let Some(value) = line.strip_prefix("Expiration Time: ") {
+                expiration_time = Some(parse_timestamp(value)?);
+            } else if let Some(value) = line.strip_prefix("Not Before: ") {
+                not_before = Some(parse_timestamp(value)?);
+            }
+        }
+
+        Some(Self {
+            domain,
+            address,
+            chain_id: chain_id?,
+            nonce: nonce?,
+            expiration_time,
+            not_before,
+        })
+    }
+}
+
+impl SiweMessage {
+    /// Whether the message is bound to this deployment and usable at `now`
+    fn is_valid(&self, domain: &str, chain_id: u64, now: chrono::DateTime<chrono::Utc>) -> bool {
+        self.domain == domain
+            && self.chain_id == chain_id
+            && self.expiration_time.is_none_or(|t| t > now)
+            && self.not_before.is_none_or(|t| t <= now)
+    }
+}
+
+fn parse_timestamp(value: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    chrono::DateTime::parse_from_rfc3339(value.trim())
+        .ok()
+        .map(|t| t.with_timezone(&chrono::Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Database;
+    use alloy::primitives::address;
+
+    const DOMAIN: &str = "monaddit.xyz";
+    const CHAIN_ID: u64 = 10143;
+
+    fn message(extra: &[&str]) -> String {
+        let mut lines = vec![
+            "monaddit.xyz wants you to sign in with your Ethereum account:",
+            "0x00000000000000000000000000000000000A11cE",
+            "",
+            "Sign in to Monaddit",
+            "",
+            "URI: https://monaddit.xyz",
+            "Version: 1",
+            "Chain ID: 10143",
+            "Nonce: 8fJ2kQ9xLm3Pz7RtA",
+            "Issued At: 2025-01-01T00:00:00Z",
+        ];
+        lines.extend_from_slice(extra);
+        lines.join("\n")
+    }
+
+    fn at(timestamp: &str) -> chrono::DateTime<chrono::Utc> {
+        parse_timestamp(timestamp).unwrap()
+    }
+
+    #[test]
+    fn parses_valid_message() {
+        let message = SiweMessage::parse(&message(&[
+            "Expiration Time: 2025-01-01T00:10:00Z",
+            "Not Before: 2025-01-01T00:00:00Z",
+        ]))
+        .unwrap();
+
+        assert_eq!(message.domain, DOMAIN);
+        assert_eq!(
+            message.address,
+            address!("00000000000000000000000000000000000a11ce")
+        );
+        assert_eq!(message.chain_id, CHAIN_ID);
+        assert_eq!(message.nonce, "8fJ2kQ9xLm3Pz7RtA");
+        assert_eq!(message.expiration_time, Some(at("2025-01-01T00:10:00Z")));
+        assert_eq!(message.not_before, Some(at("2025-01-01T00:00:00Z")));
+        assert!(message.is_valid(DOMAIN, CHAIN_ID, at("2025-01-01T00:05:00Z")));
+    }
+
+    #[test]
+    fn rejects_missing_field() {
+        let without_nonce = message(&[]).replace("Nonce: 8fJ2kQ9xLm3Pz7RtA\n", "");
+        assert!(SiweMessage::parse(&without_nonce).is_none());
+
+        let without_chain_id = message(&[]).replace("Chain ID: 10143\n", "");
+        assert!(SiweMessage::parse(&without_chain_id).is_none());
+
+        let without_address =
+            message(&[]).replace("0x00000000000000000000000000000000000A11cE", "");
+        assert!(SiweMessage::parse(&without_address).is_none());
+    }
+
+    #[test]
+    fn rejects_wrong_chain_id_and_domain() {
+        let message = SiweMessage::parse(&message(&[])).unwrap();
+        let now = at("2025-01-01T00:05:00Z");
+
+        assert!(!message.is_valid(DOMAIN, 1, now));
+        assert!(!message.is_valid("evil.example", CHAIN_ID, now));
+    }
+
+    #[test]
+    fn rejects_expired_and_not_yet_valid_message() {
+        let message = SiweMessage::parse(&message(&[
+            "Expiration Time: 2025-01-01T00:10:00Z",
+            "Not Before: 2025-01-01T00:01:00Z",
+        ]))
+        .unwrap();
+
+        assert!(!message.is_valid(DOMAIN, CHAIN_ID, at("2025-01-01T00:10:00Z")));
+        assert!(!message.is_valid(DOMAIN, CHAIN_ID, at("2025-01-01T00:00:30Z")));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires postgres at DATABASE_URL"]
+    async fn nonce_cannot_be_reused() {
+        let db = Database::new(&std::env::var("DATABASE_URL").unwrap())
+            .await
+            .unwrap();
+        let nonce: String = rand::rng()
+            .sample_iter(&Alphanumeric)
+            .take(NONCE_LENGTH)
+            .map(char::from)
+            .collect();
+
+        db.create_auth_nonce(&nonce, chrono::Utc::now() + chrono::Duration::minutes(1))
+            .await
+            .unwrap();
+
+        assert!(db.consume_auth_nonce(&nonce).await.unwrap());
+        assert!(!db.consume_auth_nonce(&nonce).await.unwrap());
+    }
+}
@@ -0,0 +1,135 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    models::{ChallengeReason, ChallengeSummary, Dispute},
+    AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct ChallengeQuery {
+    /// open or resolved
+    pub status: Option<String>,
+    pub challenger: Option<String>,
+    /// Rejected with 400 unless it is one of the contract's reasons
+    pub reason: Option<ChallengeReason>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ChallengeResponse {
+    #[serde(flatten)]
+    pub challenge: ChallengeSummary,
+    pub reason_label: &'static str,
+}
+
+impl From<ChallengeSummary> for ChallengeResponse {
+    fn from(challenge: ChallengeSummary) -> Self {
+        Self {
+            reason_label: challenge.reason.label(),
+            challenge,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct DisputeDetail {
+    #[serde(flatten)]
+    pub dispute: Dispute,
+    pub challenge: Option<ChallengeResponse>,
+}
+
+pub async fn list_content_challenges(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, StatusCode> {
+    state
+        .db
+        .get_content(id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    let challenges = state
+        .db
+        .list_challenges(Some(id), None, None, None, 100, 0)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(
+        challenges
+            .into_iter()
+            .map(ChallengeResponse::from)
+            .collect::<Vec<_>>(),
+    ))
+}
+
+pub async fn list_challenges(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<ChallengeQuery>,
+) -> Result<impl IntoResponse, StatusCode> {
+    let limit = query.limit.unwrap_or(20).clamp(1, 100);
+    let offset = query.offset.unwrap_or(0).max(0);
+
+    let resolved = match query.status.as_deref() {
+        None => None,
+        Some("open") => Some(false),
+        Some("resolved") => Some(true),
+        Some(_) => return Err(StatusCode::BAD_REQUEST),
+    };
+
+    // Addresses are stored lowercase
+    let challenger = query
+        .challenger
+        .map(|address| {
+            alloy::primitives::Address::from_str(&address)
+                .map(|address| format!("{:?}", address))
+                .map_err(|_| StatusCode::BAD_REQUEST)
+        })
+        .transpose()?;
+
+    let challenges = state
+        .db
+        .list_challenges(None, resolved, challenger, query.reason, limit, offset)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(
+        challenges
+            .into_iter()
+            .map(ChallengeResponse::from)
+            .collect::<Vec<_>>(),
+    ))
+}
+
+pub async fn get_dispute(
+    State(state): State<Arc<AppState>>,
+    Path(dispute_id): Path<i64>,
+) -> Result<impl IntoResponse, StatusCode> {
+    let dispute = state
+        .db
+        .get_dispute(dispute_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    let challenge = state
+        .db
+        .get_dispute_challenge(dispute_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(DisputeDetail {
+        dispute,
+        challenge: challenge.map(ChallengeResponse::from),
+    }))
+}
@@ -0,0 +1,175 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    models::{CommentRow, CommentSort},
+    pagination::{Cursor, Page},
+    AppState,
+};
+
+const MAX_DEPTH: i32 = 8;
+
+#[derive(Debug, Deserialize)]
+pub struct CommentQuery {
+    #[serde(default)]
+    pub sort: CommentSort,
+    /// Levels of replies to include, counting direct replies as 1
+    pub depth: Option<i32>,
+    /// Page size for direct replies to the requested content
+    pub limit: Option<i64>,
+    /// `next_cursor` or `prev_cursor` from a previous page
+    pub cursor: Option<String>,
+    /// Replies kept per comment below the first level
+    pub replies_limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CommentNode {
+    pub id: Uuid,
+    pub content_id: i64,
+    pub parent_id: Uuid,
+    /// `None` while the comment is collapsed
+    pub author_address: Option<String>,
+    pub body: Option<String>,
+    pub status: String,
+    pub published_at: chrono::DateTime<chrono::Utc>,
+    pub depth: i32,
+    #[serde(skip)]
+    pub sort_key: f64,
+    pub upvotes: i64,
+    pub downvotes: i64,
+    /// Reputation-weighted totals, in hundredths of a vote
+    pub weighted_upvotes: i64,
+    pub weighted_downvotes: i64,
+    /// challenged or slashed when the comment is hidden behind a placeholder
+    pub collapsed: Option<&'static str>,
+    pub replies_count: i64,
+    /// Replies not included in `replies`; fetch them from this comment's own
+    /// comments endpoint
+    pub more_replies: i64,
+    pub replies: Vec<CommentNode>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CommentThread {
+    pub content_id: Uuid,
+    pub sort: CommentSort,
+    /// Direct replies to the requested content
+    pub total: i64,
+    #[serde(flatten)]
+    pub comments: Page<CommentNode>,
+}
+
+pub async fn get_comments(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<CommentQuery>,
+) -> Result<impl IntoResponse, StatusCode> {
+    let depth = query.depth.unwrap_or(3).clamp(1, MAX_DEPTH);
+    let limit = query.limit.unwrap_or(20).clamp(1, 100);
+    let replies_limit = query.replies_limit.unwrap_or(5).clamp(0, 50);
+
+    let scope = format!("comments:{}:{}", id, query.sort.as_str());
+    let cursor = query
+        .cursor
+        .map(|token| Cursor::decode(&token, &scope, &state.config))
+        .transpose()
+        .map_err(|_| StatusCode::BAD_REQUEST)?;
+
+    state
+        .db
+        .get_content(id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    let rows = state
+        .db
+        .get_comment_thread(
+            id,
+            depth,
+            query.sort,
+            cursor.as_ref(),
+            limit + 1,
+            replies_limit,
+        )
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let total = state
+        .db
+        .count_comments(id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    // Rows arrive ranked within each parent, so pushing in order keeps the sort
+    let mut children: HashMap<Uuid, Vec<CommentRow>> = HashMap::new();
+    for row in rows {
+        children.entry(row.parent_id).or_default().push(row);
+    }
+
+    let comments = Page::new(
+        build_tree(id, &mut children),
+        limit,
+        cursor.as_ref(),
+        &scope,
+        &state.config,
+        |node| (node.sort_key, node.published_at, node.id),
+    )
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(CommentThread {
+        content_id: id,
+        sort: query.sort,
+        total,
+        comments,
+    }))
+}
+
+/// Replies whose parent was cut by pagination are never reached and dropped
+fn build_tree(parent_id: Uuid, children: &mut HashMap<Uuid, Vec<CommentRow>>) -> Vec<CommentNode> {
+    let Some(rows) = children.remove(&parent_id) else {
+        return Vec::new();
+    };
+
+    rows.into_iter()
+        .map(|row| {
+            let replies = build_tree(row.id, children);
+            let collapsed = if row.slashed {
+                Some("slashed")
+            } else if row.status == "challenged" || row.status == "disputed" {
+                Some("challenged")
+            } else {
+                None
+            };
+
+            CommentNode {
+                id: row.id,
+                content_id: row.content_id,
+                parent_id: row.parent_id,
+                author_address: collapsed.is_none().then_some(row.author_address),
+                body: collapsed.is_none().then_some(row.body),
+                status: row.status,
+                published_at: row.published_at,
+                depth: row.depth,
+                sort_key: row.sort_key,
+                upvotes: row.upvotes,
+                downvotes: row.downvotes,
+                weighted_upvotes: row.weighted_upvotes,
+                weighted_downvotes: row.weighted_downvotes,
+                collapsed,
+                replies_count: row.replies_count,
+                more_replies: row.replies_count - replies.len() as i64,
+                replies,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,220 @@
+use alloy::primitives::Address;
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+use sqlx::types::BigDecimal;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::{
+    middleware::auth::AuthUser,
+    models::{Community, CreateCommunityRequest, UpdateCommunityRequest, CONTENT_TYPES},
+    AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct CommunityListQuery {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+pub async fn create_community(
+    State(state): State<Arc<AppState>>,
+    AuthUser(creator): AuthUser,
+    Json(req): Json<CreateCommunityRequest>,
+) -> Result<impl IntoResponse, StatusCode> {
+    if !valid_slug(&req.slug) || req.name.trim().is_empty() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    let community = Community {
+        slug: req.slug,
+        name: req.name,
+        description: req.description,
+        rules: req.rules,
+        icon_url: req.icon_url,
+        min_stake: parse_stake(req.min_stake.as_deref().unwrap_or("0"))?,
+        allowed_content_types: content_types(
+            req.allowed_content_types
+                .unwrap_or_else(|| CONTENT_TYPES.map(String::from).to_vec()),
+        )?,
+        creator_address: format!("{:?}", creator),
+        member_count: 0,
+        created_at: chrono::Utc::now(),
+        updated_at: chrono::Utc::now(),
+    };
+
+    let created = state
+        .db
+        .create_community(&community)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    if !created {
+        return Err(StatusCode::CONFLICT);
+    }
+
+    // The creator is the first member
+    state
+        .db
+        .join_community(&community.slug, &community.creator_address)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(community_or_404(&state, &community.slug).await?),
+    ))
+}
+
+pub async fn list_communities(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<CommunityListQuery>,
+) -> Result<impl IntoResponse, StatusCode> {
+    let limit = query.limit.unwrap_or(20).clamp(1, 100);
+    let offset = query.offset.unwrap_or(0).max(0);
+
+    let communities = state
+        .db
+        .list_communities(None, limit, offset)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(communities))
+}
+
+pub async fn get_community(
+    State(state): State<Arc<AppState>>,
+    Path(slug): Path<String>,
+) -> Result<impl IntoResponse, StatusCode> {
+    Ok(Json(community_or_404(&state, &slug).await?))
+}
+
+pub async fn update_community(
+    State(state): State<Arc<AppState>>,
+    AuthUser(user): AuthUser,
+    Path(slug): Path<String>,
+    Json(update): Json<UpdateCommunityRequest>,
+) -> Result<impl IntoResponse, StatusCode> {
+    let mut community = community_or_404(&state, &slug).await?;
+
+    // Only the creator may edit a community
+    if community.creator_address != format!("{:?}", user) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    if let Some(name) = update.name {
+        if name.trim().is_empty() {
+            return Err(StatusCode::BAD_REQUEST);
+        }
+        community.name = name;
+    }
+    if let Some(description) = update.description {
+        community.description = Some(description);
+    }
+    if let Some(rules) = update.rules {
+        community.rules = rules;
+    }
+    if let Some(icon_url) = update.icon_url {
+        community.icon_url = Some(icon_url);
+    }
+    if let Some(min_stake) = update.min_stake {
+        community.min_stake = parse_stake(&min_stake)?;
+    }
+    if let Some(allowed_content_types) = update.allowed_content_types {
+        community.allowed_content_types = content_types(allowed_content_types)?;
+    }
+
+    state
+        .db
+        .update_community(&community)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(community_or_404(&state, &slug).await?))
+}
+
+pub async fn join_community(
+    State(state): State<Arc<AppState>>,
+    AuthUser(user): AuthUser,
+    Path(slug): Path<String>,
+) -> Result<impl IntoResponse, StatusCode> {
+    community_or_404(&state, &slug).await?;
+
+    state
+        .db
+        .join_community(&slug, &format!("{:?}", user))
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(StatusCode::OK)
+}
+
+pub async fn leave_community(
+    State(state): State<Arc<AppState>>,
+    AuthUser(user): AuthUser,
+    Path(slug): Path<String>,
+) -> Result<impl IntoResponse, StatusCode> {
+    community_or_404(&state, &slug).await?;
+
+    state
+        .db
+        .leave_community(&slug, &format!("{:?}", user))
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(StatusCode::OK)
+}
+
+pub async fn get_user_communities(
+    State(state): State<Arc<AppState>>,
+    Path(address): Path<String>,
+    Query(query): Query<CommunityListQuery>,
+) -> Result<impl IntoResponse, StatusCode> {
+    let member = Address::from_str(&address).map_err(|_| StatusCode::BAD_REQUEST)?;
+    let limit = query.limit.unwrap_or(20).clamp(1, 100);
+    let offset = query.offset.unwrap_or(0).max(0);
+
+    let communities = state
+        .db
+        .list_communities(Some(&format!("{:?}", member)), limit, offset)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(communities))
+}
+
+async fn community_or_404(state: &AppState, slug: &str) -> Result<Community, StatusCode> {
+    state
+        .db
+        .get_community(slug)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+/// 3-50 lowercase letters, digits, `-` or `_`
+fn valid_slug(slug: &str) -> bool {
+    (3..=50).contains(&slug.len())
+        && slug
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+}
+
+fn parse_stake(amount: &str) -> Result<BigDecimal, StatusCode> {
+    let amount = BigDecimal::from_str(amount).map_err(|_| StatusCode::BAD_REQUEST)?;
+    if amount < BigDecimal::from(0) || !amount.is_integer() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    Ok(amount)
+}
+
+fn content_types(types: Vec<String>) -> Result<Vec<String>, StatusCode> {
+    if types.is_empty() || !types.iter().all(|t| CONTENT_TYPES.contains(&t.as_str())) {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    Ok(types)
+}
@@ -1,3 +1,4 @@
+use alloy::primitives::{Address, B256, U256};
 use axum::{
     extract::{Path, Query, State},
     http::StatusCode,
@@ -5,41 +6,82 @@ use axum::{
     Json,
 };
 use serde::{Deserialize, Serialize};
-use sha3::{Digest, Keccak256};
 use sqlx::types::BigDecimal;
 use std::str::FromStr;
 use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::{
-    models::{Content, CreateContentRequest, CreateContentResponse},
+    chain::client::ContentInfo,
+    hashing::{self, CURRENT_HASH_VERSION},
+    middleware::auth::AuthUser,
+    models::{
+        Content, CreateContentRequest, CreateContentResponse, FeedSort, FeedWindow, PlatformStats,
+        CONTENT_TYPES,
+    },
+    pagination::{Cursor, Page},
     AppState,
 };
 
 #[derive(Debug, Deserialize)]
 pub struct ListQuery {
     pub limit: Option<i64>,
-    pub offset: Option<i64>,
+    /// `next_cursor` or `prev_cursor` from a previous page
+    pub cursor: Option<String>,
     pub community_id: Option<String>,
+    #[serde(default)]
+    pub sort: FeedSort,
+    /// Publication window for `top` and `controversial`
+    #[serde(default)]
+    pub window: FeedWindow,
 }
 
 pub async fn create_content(
     State(state): State<Arc<AppState>>,
+    AuthUser(author): AuthUser,
     Json(req): Json<CreateContentRequest>,
 ) -> Result<impl IntoResponse, StatusCode> {
-    // Generate content hash
-    let mut hasher = Keccak256::new();
-    hasher.update(req.title.as_bytes());
-    hasher.update(req.body.as_bytes());
-    let hash_result = hasher.finalize();
-    let content_hash = format!("0x{}", hex::encode(hash_result));
+    if !CONTENT_TYPES.contains(&req.content_type.as_str()) {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    // Posts must target an existing community that accepts them
+    match &req.community_id {
+        Some(community_id) => {
+            let community = state
+                .db
+                .get_community(community_id)
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+                .ok_or(StatusCode::UNPROCESSABLE_ENTITY)?;
+
+            if !community.allowed_content_types.contains(&req.content_type) {
+                return Err(StatusCode::UNPROCESSABLE_ENTITY);
+            }
+
+            if community.min_stake > BigDecimal::from(0) {
+                let stake = state
+                    .chain_client
+                    .get_stake_info(author)
+                    .await
+                    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+                let total_stake = BigDecimal::from_str(&stake.total_amount.to_string())
+                    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+                if total_stake < community.min_stake {
+                    return Err(StatusCode::FORBIDDEN);
+                }
+            }
+        }
+        None if req.content_type == "post" => return Err(StatusCode::UNPROCESSABLE_ENTITY),
+        None => {}
+    }
 
     // Create content in database
-    let content = Content {
+    let mut content = Content {
         id: Uuid::new_v4(),
-        content_id: 0, // Will be updated after on-chain publication
-        author_address: "0x0000000000000000000000000000000000000000".to_string(), // Will be set from wallet
-        content_hash: content_hash.clone(),
+        content_id: None, // Bound by the listener from ContentPublished
+        author_address: format!("{:?}", author),
+        content_hash: String::new(),
         title: req.title,
         body: req.body,
         uri: None,
@@ -52,8 +94,14 @@ pub async fn create_content(
         lock_until: Some(chrono::Utc::now() + chrono::Duration::days(7)),
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
+        hash_version: CURRENT_HASH_VERSION,
     };
 
+    // Generate content hash
+    let content_hash = hashing::content_hash(&state.config, &content)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    content.content_hash = format!("{:?}", content_hash);
+
     let content_id = state
         .db
         .create_content(content.clone())
@@ -62,7 +110,8 @@ pub async fn create_content(
 
     Ok(Json(CreateContentResponse {
         id: content_id,
-        content_hash,
+        content_hash: content.content_hash,
+        hash_version: content.hash_version,
         estimated_gas: Some("200000".to_string()),
     }))
 }
@@ -87,26 +136,152 @@ pub async fn list_contents(
     State(state): State<Arc<AppState>>,
     Query(query): Query<ListQuery>,
 ) -> Result<impl IntoResponse, StatusCode> {
-    let limit = query.limit.unwrap_or(20).min(100);
-    let offset = query.offset.unwrap_or(0);
-    let community_id = query.community_id.unwrap_or_else(|| "default".to_string());
+    let limit = query.limit.unwrap_or(20).clamp(1, 100);
+
+    // Without a community the feed spans all of them
+    if let Some(community_id) = &query.community_id {
+        state
+            .db
+            .get_community(community_id)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+            .ok_or(StatusCode::NOT_FOUND)?;
+    }
+
+    let scope = format!(
+        "contents:{}:{}:{:?}",
+        query.community_id.as_deref().unwrap_or("*"),
+        query.sort.as_str(),
+        query.window
+    );
+    let cursor = query
+        .cursor
+        .map(|token| Cursor::decode(&token, &scope, &state.config))
+        .transpose()
+        .map_err(|_| StatusCode::BAD_REQUEST)?;
+
+    let published_since = match query.sort {
+        FeedSort::Top | FeedSort::Controversial => query.window.since(),
+        FeedSort::Rising => FeedWindow::Day.since(),
+        FeedSort::New | FeedSort::Hot => None,
+    };
 
     let contents = state
         .db
-        .get_contents_by_community(&community_id, limit, offset)
+        .get_contents_by_community(
+            query.community_id.as_deref(),
+            query.sort,
+            published_since,
+            cursor.as_ref(),
+            limit + 1,
+        )
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
-    Ok(Json(contents))
+    let page = Page::new(
+        contents,
+        limit,
+        cursor.as_ref(),
+        &scope,
+        &state.config,
+        |row| (row.sort_key, row.content.published_at, row.content.id),
+    )
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(page))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HashQuery {
+    pub verify: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ContentVerification {
+    /// The stored text hashes to the stored hash, which is registered on-chain
+    /// under this content's ID
+    pub verified: bool,
+    pub computed_hash: String,
+    pub body_matches_hash: bool,
+    pub on_chain_content_id: Option<String>,
+    pub on_chain_author: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ContentByHashResponse {
+    #[serde(flatten)]
+    pub content: Content,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification: Option<ContentVerification>,
 }
 
 pub async fn get_content_by_hash(
-    State(_state): State<Arc<AppState>>,
-    Path(_hash): Path<String>,
-) -> Result<Json<Content>, StatusCode> {
-    // Query database for content with this hash
-    // This is a simplified version - you'd need to add this query to the Database impl
-    Err(StatusCode::NOT_IMPLEMENTED)
+    State(state): State<Arc<AppState>>,
+    Path(hash): Path<String>,
+    Query(query): Query<HashQuery>,
+) -> Result<impl IntoResponse, StatusCode> {
+    let hash = B256::from_str(&hash).map_err(|_| StatusCode::BAD_REQUEST)?;
+
+    let content = state
+        .db
+        .get_content_by_hash(&format!("{:?}", hash))
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    let verification = if query.verify.unwrap_or(false) {
+        let computed_hash = hashing::content_hash(&state.config, &content)
+            .map(|hash| format!("{:?}", hash))
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        let body_matches_hash = computed_hash == content.content_hash;
+
+        let on_chain = state
+            .chain_client
+            .find_content_by_hash(hash)
+            .await
+            .map_err(|_| StatusCode::BAD_GATEWAY)?;
+
+        let registered = is_registered(&content, on_chain.as_ref());
+
+        Some(ContentVerification {
+            verified: body_matches_hash && registered,
+            computed_hash,
+            body_matches_hash,
+            on_chain_content_id: on_chain.as_ref().map(|(id, _)| id.to_string()),
+            on_chain_author: on_chain.map(|(_, info)| format!("{:?}", info.author)),
+        })
+    } else {
+        None
+    };
+
+    Ok(Json(ContentByHashResponse {
+        content,
+        verification,
+    }))
+}
+
+/// Whether `on_chain` is this content's own registration: the draft must be
+/// bound to the registered id and the registering author must be its author,
+/// so a hash copied into another author's registration does not verify.
+fn is_registered(content: &Content, on_chain: Option<&(U256, ContentInfo)>) -> bool {
+    on_chain.is_some_and(|(content_id, info)| {
+        content
+            .content_id
+            .is_some_and(|id| U256::from(id as u64) == *content_id)
+            && content
+                .author_address
+                .parse::<Address>()
+                .is_ok_and(|author| author == info.author)
+    })
+}
+
+/// Windows kept in the `platform_stats` rollup
+const STATS_WINDOWS: [&str; 4] = ["24h", "7d", "30d", "all"];
+
+#[derive(Debug, Deserialize)]
+pub struct StatsQuery {
+    /// 24h, 7d, 30d or all (default)
+    pub window: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -114,16 +289,177 @@ pub struct ContentStats {
     pub total_contents: i64,
     pub total_challenges: i64,
     pub total_resolved: i64,
+    pub total_guilty: i64,
+    /// Share of challenges that have been resolved
+    pub resolved_rate: f64,
+    /// Share of resolved challenges that found the content guilty
+    pub guilty_rate: f64,
+    /// Content and challenge bonds posted, in wei
+    pub total_bonded: String,
+    /// Addresses with a stake that posted a content or challenge bond
+    pub active_stakers: i64,
+}
+
+impl ContentStats {
+    fn new(stats: &PlatformStats) -> Self {
+        Self {
+            total_contents: stats.total_contents,
+            total_challenges: stats.total_challenges,
+            total_resolved: stats.total_resolved,
+            total_guilty: stats.total_guilty,
+            resolved_rate: rate(stats.total_resolved, stats.total_challenges),
+            guilty_rate: rate(stats.total_guilty, stats.total_resolved),
+            total_bonded: stats.total_bonded.to_string(),
+            active_stakers: stats.active_stakers,
+        }
+    }
+}
+
+impl Default for ContentStats {
+    fn default() -> Self {
+        Self {
+            total_contents: 0,
+            total_challenges: 0,
+            total_resolved: 0,
+            total_guilty: 0,
+            resolved_rate: 0.0,
+            guilty_rate: 0.0,
+            total_bonded: "0".to_string(),
+            active_stakers: 0,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CommunityStats {
+    /// `None` for content posted outside any community
+    pub community_id: Option<String>,
+    #[serde(flatten)]
+    pub stats: ContentStats,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StatsResponse {
+    pub window: String,
+    #[serde(flatten)]
+    pub totals: ContentStats,
+    pub communities: Vec<CommunityStats>,
+    /// When the rollup was last rebuilt; `None` before the first refresh
+    pub refreshed_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 pub async fn get_stats(
-    State(_state): State<Arc<AppState>>,
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<StatsQuery>,
 ) -> Result<impl IntoResponse, StatusCode> {
-    // Get statistics from database
-    // This is a simplified version - you'd need to add these queries to the Database impl
-    Ok(Json(ContentStats {
-        total_contents: 0,
-        total_challenges: 0,
-        total_resolved: 0,
+    let window = query.window.unwrap_or_else(|| "all".to_string());
+    if !STATS_WINDOWS.contains(&window.as_str()) {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    // Served from the rollup refreshed by the stats worker
+    let rows = state
+        .db
+        .get_platform_stats(&window)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let refreshed_at = rows.iter().map(|row| row.refreshed_at).max();
+    let totals = rows
+        .iter()
+        .find(|row| row.is_total)
+        .map(ContentStats::new)
+        .unwrap_or_default();
+    let communities = rows
+        .iter()
+        .filter(|row| !row.is_total)
+        .map(|row| CommunityStats {
+            community_id: row.community_id.clone(),
+            stats: ContentStats::new(row),
+        })
+        .collect();
+
+    Ok(Json(StatsResponse {
+        window,
+        totals,
+        communities,
+        refreshed_at,
     }))
 }
+
+fn rate(part: i64, whole: i64) -> f64 {
+    if whole == 0 {
+        0.0
+    } else {
+        part as f64 / whole as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AUTHOR: Address = Address::repeat_byte(0xaa);
+
+    fn content(content_id: Option<i64>) -> Content {
+        Content {
+            id: Uuid::new_v4(),
+            content_id,
+            author_address: format!("{:?}", AUTHOR),
+            content_hash: format!("{:?}", B256::repeat_byte(0x01)),
+            title: "Title".to_string(),
+            body: "Body".to_string(),
+            uri: None,
+            content_type: "post".to_string(),
+            parent_id: None,
+            community_id: None,
+            bond_amount: BigDecimal::from(0),
+            status: "published".to_string(),
+            published_at: chrono::Utc::now(),
+            lock_until: None,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+            hash_version: CURRENT_HASH_VERSION,
+        }
+    }
+
+    fn registration(content_id: u64, author: Address) -> (U256, ContentInfo) {
+        (
+            U256::from(content_id),
+            ContentInfo {
+                author,
+                content_hash: B256::repeat_byte(0x01),
+                uri: String::new(),
+                bond: U256::ZERO,
+                published_at: U256::ZERO,
+                lock_until: U256::ZERO,
+                status: 0,
+            },
+        )
+    }
+
+    #[test]
+    fn own_registration_verifies() {
+        assert!(is_registered(
+            &content(Some(7)),
+            Some(&registration(7, AUTHOR))
+        ));
+    }
+
+    #[test]
+    fn hash_copied_by_another_author_does_not_verify() {
+        let copied = registration(7, Address::repeat_byte(0xbb));
+
+        assert!(!is_registered(&content(Some(7)), Some(&copied)));
+        assert!(!is_registered(&content(None), Some(&copied)));
+    }
+
+    #[test]
+    fn unbound_or_mismatched_id_does_not_verify() {
+        let registered = registration(7, AUTHOR);
+
+        assert!(!is_registered(&content(None), Some(&registered)));
+        assert!(!is_registered(&content(Some(8)), Some(&registered)));
+        assert!(!is_registered(&content(Some(7)), None));
+    }
+}
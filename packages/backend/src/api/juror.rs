@@ -0,0 +1,355 @@
+use aes_gcm::{
+    aead::{Aead, KeyInit, Payload},
+    Aes256Gcm, Nonce,
+};
+use alloy::primitives::{Address, B256, U256};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::{
+    config::Config,
+    middleware::auth::AuthUser,
+    models::{JurorAssignment, SaltEscrow},
+    AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct JurorDisputesQuery {
+    /// Only return disputes that still need a commit or reveal from the juror
+    pub pending: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct JurorDispute {
+    pub dispute_id: i64,
+    pub content_id: i64,
+    /// commit, reveal, finalizing or resolved
+    pub phase: &'static str,
+    /// End of the current phase, if it is still open
+    pub deadline: Option<chrono::DateTime<chrono::Utc>>,
+    pub commit_deadline: chrono::DateTime<chrono::Utc>,
+    pub reveal_deadline: chrono::DateTime<chrono::Utc>,
+    pub has_committed: bool,
+    pub has_revealed: bool,
+    pub vote: Option<bool>,
+    pub guilty: Option<bool>,
+    pub salt_escrowed: bool,
+    /// commit or reveal when the juror owes an action in the current phase
+    pub action_required: Option<&'static str>,
+}
+
+impl JurorDispute {
+    fn new(assignment: JurorAssignment, now: chrono::DateTime<chrono::Utc>) -> Self {
+        let (phase, deadline, action_required) = if assignment.dispute_status == "resolved" {
+            ("resolved", None, None)
+        } else if now <= assignment.commit_deadline {
+            (
+                "commit",
+                Some(assignment.commit_deadline),
+                (!assignment.committed).then_some("commit"),
+            )
+        } else if now <= assignment.reveal_deadline {
+            // Only committed jurors can reveal
+            (
+                "reveal",
+                Some(assignment.reveal_deadline),
+                (assignment.committed && !assignment.revealed).then_some("reveal"),
+            )
+        } else {
+            ("finalizing", None, None)
+        };
+
+        Self {
+            dispute_id: assignment.dispute_id,
+            content_id: assignment.content_id,
+            phase,
+            deadline,
+            commit_deadline: assignment.commit_deadline,
+            reveal_deadline: assignment.reveal_deadline,
+            has_committed: assignment.committed,
+            has_revealed: assignment.revealed,
+            vote: assignment.vote,
+            guilty: assignment.guilty,
+            salt_escrowed: assignment.salt_escrowed,
+            action_required,
+        }
+    }
+}
+
+pub async fn get_juror_disputes(
+    State(state): State<Arc<AppState>>,
+    Path(address): Path<String>,
+    Query(query): Query<JurorDisputesQuery>,
+) -> Result<impl IntoResponse, StatusCode> {
+    let juror = Address::from_str(&address).map_err(|_| StatusCode::BAD_REQUEST)?;
+
+    let assignments = state
+        .db
+        .get_juror_assignments(&format!("{:?}", juror))
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let now = chrono::Utc::now();
+    let disputes: Vec<JurorDispute> = assignments
+        .into_iter()
+        .map(|assignment| JurorDispute::new(assignment, now))
+        .filter(|dispute| !query.pending.unwrap_or(false) || dispute.action_required.is_some())
+        .collect();
+
+    Ok(Json(disputes))
+}
+
+pub async fn get_juror_dispute(
+    State(state): State<Arc<AppState>>,
+    Path((address, dispute_id)): Path<(String, i64)>,
+) -> Result<impl IntoResponse, StatusCode> {
+    let juror = Address::from_str(&address).map_err(|_| StatusCode::BAD_REQUEST)?;
+
+    let mut assignment = state
+        .db
+        .get_juror_assignment(&format!("{:?}", juror), dispute_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    // The indexed vote state trails the chain by the listener's confirmation
+    // depth, so read it live to avoid telling a juror to commit twice
+    let (has_committed, has_revealed, vote) = state
+        .chain_client
+        .get_juror_vote(U256::from(dispute_id), juror)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    assignment.committed = has_committed;
+    assignment.revealed = has_revealed;
+    assignment.vote = has_revealed.then_some(vote);
+
+    Ok(Json(JurorDispute::new(assignment, chrono::Utc::now())))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CommitHashRequest {
+    pub juror: String,
+    pub vote: bool,
+    /// 32-byte hex salt; a random one is generated when omitted
+    pub salt: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CommitHashResponse {
+    pub commitment: String,
+    pub salt: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SaltEscrowRequest {
+    pub vote: bool,
+    pub salt: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SaltEscrowResponse {
+    pub dispute_id: i64,
+    pub commitment: String,
+    pub vote: bool,
+    pub salt: String,
+}
+
+pub async fn compute_commit_hash(
+    Json(req): Json<CommitHashRequest>,
+) -> Result<impl IntoResponse, StatusCode> {
+    let juror = Address::from_str(&req.juror).map_err(|_| StatusCode::BAD_REQUEST)?;
+    let salt = match &req.salt {
+        Some(salt) => B256::from_str(salt).map_err(|_| StatusCode::BAD_REQUEST)?,
+        None => B256::from(rand::random::<[u8; 32]>()),
+    };
+
+    Ok(Json(CommitHashResponse {
+        commitment: format!("{:?}", vote_commitment(req.vote, salt, juror)),
+        salt: format!("{:?}", salt),
+    }))
+}
+
+pub async fn escrow_salt(
+    State(state): State<Arc<AppState>>,
+    AuthUser(user): AuthUser,
+    Path((address, dispute_id)): Path<(String, i64)>,
+    Json(req): Json<SaltEscrowRequest>,
+) -> Result<impl IntoResponse, StatusCode> {
+    let juror = authorized_juror(&address, user)?;
+    let cipher = escrow_cipher(&state.config)?;
+    let salt = B256::from_str(&req.salt).map_err(|_| StatusCode::BAD_REQUEST)?;
+
+    // Only jurors selected for the dispute can escrow a vote for it
+    state
+        .db
+        .get_juror_assignment(&juror, dispute_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    let commitment = vote_commitment(req.vote, salt, user);
+
+    // A salt that does not open the on-chain commitment could never be revealed
+    let on_chain = state
+        .chain_client
+        .get_juror_commitment(U256::from(dispute_id), user)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    if on_chain.is_some_and(|on_chain| on_chain != commitment) {
+        return Err(StatusCode::CONFLICT);
+    }
+
+    let mut plaintext = vec![req.vote as u8];
+    plaintext.extend_from_slice(salt.as_slice());
+
+    let nonce: [u8; 12] = rand::random();
+    let ciphertext = cipher
+        .encrypt(
+            Nonce::from_slice(&nonce),
+            Payload {
+                msg: &plaintext,
+                aad: escrow_aad(dispute_id, &juror).as_bytes(),
+            },
+        )
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let commitment = format!("{:?}", commitment);
+
+    state
+        .db
+        .upsert_salt_escrow(SaltEscrow {
+            dispute_id,
+            juror_address: juror,
+            commitment: commitment.clone(),
+            ciphertext: hex::encode(ciphertext),
+            nonce: hex::encode(nonce),
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        })
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(SaltEscrowResponse {
+        dispute_id,
+        commitment,
+        vote: req.vote,
+        salt: format!("{:?}", salt),
+    }))
+}
+
+pub async fn get_escrowed_salt(
+    State(state): State<Arc<AppState>>,
+    AuthUser(user): AuthUser,
+    Path((address, dispute_id)): Path<(String, i64)>,
+) -> Result<impl IntoResponse, StatusCode> {
+    let juror = authorized_juror(&address, user)?;
+    let cipher = escrow_cipher(&state.config)?;
+
+    let escrow = state
+        .db
+        .get_salt_escrow(dispute_id, &juror)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    let nonce = hex::decode(&escrow.nonce).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let ciphertext =
+        hex::decode(&escrow.ciphertext).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let plaintext = cipher
+        .decrypt(
+            Nonce::from_slice(&nonce),
+            Payload {
+                msg: &ciphertext,
+                aad: escrow_aad(dispute_id, &juror).as_bytes(),
+            },
+        )
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let (vote, salt) = plaintext
+        .split_first()
+        .ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
+    let salt = B256::try_from(salt).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(SaltEscrowResponse {
+        dispute_id,
+        commitment: escrow.commitment,
+        vote: *vote == 1,
+        salt: format!("{:?}", salt),
+    }))
+}
+
+/// `keccak256(abi.encodePacked(vote, salt, juror))`, as checked by
+/// `ModerationGame.revealVote`
+fn vote_commitment(vote: bool, salt: B256, juror: Address) -> B256 {
+    let mut hasher = Keccak256::new();
+    hasher.update([vote as u8]);
+    hasher.update(salt.as_slice());
+    hasher.update(juror.as_slice());
+    B256::from_slice(&hasher.finalize())
+}
+
+/// Jurors may only read and write their own escrow
+fn authorized_juror(address: &str, user: Address) -> Result<String, StatusCode> {
+    let juror = Address::from_str(address).map_err(|_| StatusCode::BAD_REQUEST)?;
+    if juror != user {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    Ok(format!("{:?}", juror))
+}
+
+fn escrow_cipher(config: &Config) -> Result<Aes256Gcm, StatusCode> {
+    let key = config
+        .salt_escrow_key
+        .as_ref()
+        .ok_or(StatusCode::SERVICE_UNAVAILABLE)?;
+    let key =
+        hex::decode(key.trim_start_matches("0x")).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Aes256Gcm::new_from_slice(&key).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+/// Binds a ciphertext to its row so it cannot be replayed for another dispute or juror
+fn escrow_aad(dispute_id: i64, juror: &str) -> String {
+    format!("{}:{}", dispute_id, juror)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::{
+        primitives::{address, b256, keccak256},
+        sol_types::SolValue,
+    };
+
+    const JUROR: Address = address!("00000000000000000000000000000000000a11ce");
+    const SALT: B256 = b256!("1111111111111111111111111111111111111111111111111111111111111111");
+
+    #[test]
+    fn vote_commitment_matches_known_vectors() {
+        assert_eq!(
+            vote_commitment(true, SALT, JUROR),
+            b256!("93f0aa782b95b7b8543555ec4b44ff3d2597289ead04b8cb59d778f23b0e6a91")
+        );
+        assert_eq!(
+            vote_commitment(false, SALT, JUROR),
+            b256!("639eeac03d99a38b04b9b9d30c96e227d3e3296bf1c1514e775fc1caa4e6bace")
+        );
+    }
+
+    #[test]
+    fn vote_commitment_matches_abi_encode_packed() {
+        for vote in [true, false] {
+            assert_eq!(
+                vote_commitment(vote, SALT, JUROR),
+                keccak256((vote, SALT, JUROR).abi_encode_packed())
+            );
+        }
+    }
+}
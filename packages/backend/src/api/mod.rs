@@ -1,5 +1,11 @@
+pub mod auth;
+pub mod challenge;
+pub mod comment;
+pub mod community;
 pub mod content;
+pub mod juror;
 pub mod score;
+pub mod search;
 pub mod user;
 pub mod vote;
 
@@ -0,0 +1,79 @@
+use alloy::primitives::Address;
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::{
+    models::SearchFilter,
+    pagination::{Cursor, Page},
+    AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct SearchQuery {
+    /// Web-search syntax: quoted phrases, `or`, and `-` to exclude words
+    pub q: String,
+    pub community_id: Option<String>,
+    pub author: Option<String>,
+    pub status: Option<String>,
+    /// RFC 3339 bounds on the publication time
+    pub from: Option<chrono::DateTime<chrono::Utc>>,
+    pub to: Option<chrono::DateTime<chrono::Utc>>,
+    pub limit: Option<i64>,
+    /// `next_cursor` or `prev_cursor` from a previous page
+    pub cursor: Option<String>,
+}
+
+pub async fn search(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<SearchQuery>,
+) -> Result<impl IntoResponse, StatusCode> {
+    let q = query.q.trim();
+    if q.is_empty() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    let limit = query.limit.unwrap_or(20).clamp(1, 100);
+
+    let filter = SearchFilter {
+        community_id: query.community_id,
+        author_address: query
+            .author
+            .map(|author| Address::from_str(&author).map(|a| format!("{:?}", a)))
+            .transpose()
+            .map_err(|_| StatusCode::BAD_REQUEST)?,
+        status: query.status,
+        published_after: query.from,
+        published_before: query.to,
+    };
+
+    let scope = format!("search:{}:{:?}", q, filter);
+    let cursor = query
+        .cursor
+        .map(|token| Cursor::decode(&token, &scope, &state.config))
+        .transpose()
+        .map_err(|_| StatusCode::BAD_REQUEST)?;
+
+    let results = state
+        .db
+        .search_contents(q, &filter, cursor.as_ref(), limit + 1)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let page = Page::new(
+        results,
+        limit,
+        cursor.as_ref(),
+        &scope,
+        &state.config,
+        |result| (result.rank, result.published_at, result.id),
+    )
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(page))
+}
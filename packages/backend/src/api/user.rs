@@ -1,6 +1,6 @@
 use alloy::primitives::Address;
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
     Json,
@@ -8,8 +8,22 @@ use axum::{
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 use std::sync::Arc;
+use tracing::warn;
 
-use crate::AppState;
+use crate::{
+    middleware::auth::AuthUser,
+    models::UpdateProfileRequest,
+    pagination::{Cursor, Page},
+    workers::rewards::calculate_user_rewards,
+    AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct HistoryQuery {
+    pub limit: Option<i64>,
+    /// `next_cursor` or `prev_cursor` from a previous page
+    pub cursor: Option<String>,
+}
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct UserProfile {
@@ -19,6 +33,9 @@ pub struct UserProfile {
     pub total_stake: String,
     pub reputation_multiplier: i32,
     pub pending_rewards: String,
+    /// Rewards from finalized epochs that have not been claimed yet, or
+    /// `null` when they could not be read
+    pub unclaimed_rewards: Option<String>,
     pub is_eligible_staker: bool,
 }
 
@@ -26,59 +43,111 @@ pub async fn get_user_profile(
     State(state): State<Arc<AppState>>,
     Path(address): Path<String>,
 ) -> Result<impl IntoResponse, StatusCode> {
-    // Get on-chain data
+    // Get on-chain data in a single multicall
     let user_address = Address::from_str(&address).map_err(|_| StatusCode::BAD_REQUEST)?;
 
-    let stake_info = state
-        .chain_client
-        .get_stake_info(user_address)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    // Reward batches are read alongside the profile multicall, not after it
+    let (profile, unclaimed_rewards) = tokio::join!(
+        state.chain_client.get_on_chain_profile(user_address),
+        calculate_user_rewards(&state, user_address)
+    );
+    let profile = profile.map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
-    let (karma, _dispute_rate) = state
-        .chain_client
-        .get_reputation(user_address)
-        .await
-        .unwrap_or((
-            alloy::primitives::U256::from(100),
-            alloy::primitives::U256::ZERO,
-        ));
-
-    let reputation_multiplier = state
-        .chain_client
-        .get_reputation_multiplier(user_address)
-        .await
+    let (karma, _dispute_rate) = profile.reputation.unwrap_or((
+        alloy::primitives::U256::from(100),
+        alloy::primitives::U256::ZERO,
+    ));
+    let reputation_multiplier = profile
+        .reputation_multiplier
         .unwrap_or(alloy::primitives::U256::from(100));
-
-    let pending_rewards = state
-        .chain_client
-        .get_pending_rewards(user_address)
-        .await
+    let pending_rewards = profile
+        .pending_rewards
         .unwrap_or(alloy::primitives::U256::ZERO);
+    let is_eligible = profile.is_eligible_staker.unwrap_or(false);
 
-    let is_eligible = state
-        .chain_client
-        .is_eligible_staker(user_address)
+    // The rest of the profile is still useful without unclaimed rewards
+    let unclaimed_rewards = unclaimed_rewards
+        .inspect_err(|e| {
+            warn!(
+                "Failed to read unclaimed rewards of {:?}: {}",
+                user_address, e
+            )
+        })
+        .ok();
+
+    let username = state
+        .db
+        .get_username(&format!("{:?}", user_address))
         .await
-        .unwrap_or(false);
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
     Ok(Json(UserProfile {
         address: address.clone(),
-        username: None,
-        karma: karma.to::<i32>(),
-        total_stake: stake_info.total_amount.to_string(),
-        reputation_multiplier: reputation_multiplier.to::<i32>(),
+        username,
+        karma: i32::try_from(karma).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?,
+        total_stake: profile.stake.total_amount.to_string(),
+        reputation_multiplier: i32::try_from(reputation_multiplier)
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?,
         pending_rewards: pending_rewards.to_string(),
+        unclaimed_rewards: unclaimed_rewards.map(|rewards| rewards.to_string()),
         is_eligible_staker: is_eligible,
     }))
 }
 
 pub async fn update_user_profile(
     State(state): State<Arc<AppState>>,
+    AuthUser(user): AuthUser,
     Path(address): Path<String>,
-    Json(update): Json<serde_json::Value>,
+    Json(update): Json<UpdateProfileRequest>,
 ) -> Result<impl IntoResponse, StatusCode> {
-    // Update user profile in database
-    // This would typically require authentication
+    // Users may only edit their own profile
+    let user_address = Address::from_str(&address).map_err(|_| StatusCode::BAD_REQUEST)?;
+    if user_address != user {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    state
+        .db
+        .update_username(&format!("{:?}", user), update.username)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
     Ok(StatusCode::OK)
 }
+
+pub async fn get_user_contents(
+    State(state): State<Arc<AppState>>,
+    Path(address): Path<String>,
+    Query(query): Query<HistoryQuery>,
+) -> Result<impl IntoResponse, StatusCode> {
+    let author = format!(
+        "{:?}",
+        Address::from_str(&address).map_err(|_| StatusCode::BAD_REQUEST)?
+    );
+    let limit = query.limit.unwrap_or(20).clamp(1, 100);
+
+    let scope = format!("user:{}", author);
+    let cursor = query
+        .cursor
+        .map(|token| Cursor::decode(&token, &scope, &state.config))
+        .transpose()
+        .map_err(|_| StatusCode::BAD_REQUEST)?;
+
+    let contents = state
+        .db
+        .get_contents_by_author(&author, cursor.as_ref(), limit + 1)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let page = Page::new(
+        contents,
+        limit,
+        cursor.as_ref(),
+        &scope,
+        &state.config,
+        |content| (0.0, content.published_at, content.id),
+    )
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(page))
+}
@@ -7,19 +7,31 @@ use axum::{
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::{models::VoteRequest, AppState};
+use crate::{middleware::auth::AuthUser, models::VoteRequest, AppState};
 
 pub async fn create_vote(
     State(state): State<Arc<AppState>>,
+    AuthUser(voter): AuthUser,
     Path(content_id): Path<Uuid>,
     Json(req): Json<VoteRequest>,
 ) -> Result<impl IntoResponse, StatusCode> {
-    // TODO: Get voter address from authenticated user
-    let voter_address = "0x0000000000000000000000000000000000000000".to_string();
+    let voter_address = format!("{:?}", voter);
+
+    // Only stakers and SBT holders may vote, so fresh wallets carry no weight
+    let power = state
+        .chain_client
+        .get_voting_power(voter)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    if !power.can_vote() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    let weight = i32::try_from(power.reputation_multiplier)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
     state
         .db
-        .create_vote(content_id, voter_address, req.vote_type)
+        .create_vote(content_id, voter_address, req.vote_type, weight)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
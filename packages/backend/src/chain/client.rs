@@ -1,6 +1,7 @@
 use alloy::{
-    primitives::{address, Address, B256, U256},
-    providers::{Provider, ProviderBuilder},
+    eips::BlockNumberOrTag,
+    primitives::{Address, B256, U256},
+    providers::{CallItemBuilder, Provider, ProviderBuilder},
 };
 
 use crate::chain::contracts::*;
@@ -10,6 +11,9 @@ use anyhow::Result;
 // DynProvider is the erased provider type
 type DynProvider = alloy::providers::DynProvider;
 
+/// Epochs read per Multicall3 call by `get_user_epoch_infos`
+const EPOCH_INFO_BATCH_SIZE: usize = 200;
+
 #[derive(Clone)]
 pub struct ChainClient {
     provider: DynProvider,
@@ -40,6 +44,24 @@ impl ChainClient {
         Ok(block)
     }
 
+    pub async fn get_latest_block_timestamp(&self) -> Result<u64> {
+        let block = self
+            .provider
+            .get_block_by_number(BlockNumberOrTag::Latest)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("latest block not found"))?;
+        Ok(block.header.timestamp)
+    }
+
+    pub async fn get_block_timestamp(&self, block_number: u64) -> Result<u64> {
+        let block = self
+            .provider
+            .get_block_by_number(BlockNumberOrTag::Number(block_number))
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("block {} not found", block_number))?;
+        Ok(block.header.timestamp)
+    }
+
     // Contract interaction methods
     pub async fn get_content_info(&self, content_id: U256) -> Result<ContentInfo> {
         let contract = ContentRegistry::new(
@@ -47,36 +69,97 @@ impl ChainClient {
             &self.provider,
         );
 
-        // This would call the getContent method
-        // The actual method names depend on your contract ABI
-        // let result = contract.getContent(content_id).call().await?;
+        let result = contract.getContent(content_id).call().await?;
 
-        // Placeholder for now
         Ok(ContentInfo {
-            author: address!("0000000000000000000000000000000000000000"),
-            content_hash: B256::default(),
-            uri: String::new(),
-            bond: U256::ZERO,
-            published_at: U256::ZERO,
-            lock_until: U256::ZERO,
-            status: 0,
+            author: result.author,
+            content_hash: result.contentHash,
+            uri: result.uri,
+            bond: result.bond,
+            published_at: result.publishedAt,
+            lock_until: result.lockUntil,
+            status: result.status,
         })
     }
 
+    /// Looks up the content registered under `content_hash`. The registry
+    /// returns 0 for unknown hashes, which is also a valid ID, so the stored
+    /// hash is checked before returning.
+    pub async fn find_content_by_hash(
+        &self,
+        content_hash: B256,
+    ) -> Result<Option<(U256, ContentInfo)>> {
+        let contract = ContentRegistry::new(
+            self.config.content_registry_address.parse()?,
+            &self.provider,
+        );
+
+        let content_id = contract.hashToContentId(content_hash).call().await?;
+        let info = self.get_content_info(content_id).await?;
+
+        Ok((info.content_hash == content_hash).then_some((content_id, info)))
+    }
+
+    pub async fn get_dispute(&self, dispute_id: U256) -> Result<DisputeInfo> {
+        let contract =
+            ModerationGame::new(self.config.moderation_game_address.parse()?, &self.provider);
+
+        let result = contract.disputes(dispute_id).call().await?;
+
+        Ok(DisputeInfo {
+            content_id: result.contentId,
+            challenger: result.challenger,
+            challenger_bond: result.challengerBond,
+            status: result.status,
+            commit_deadline: result.commitDeadline,
+            reveal_deadline: result.revealDeadline,
+            guilty_votes: result.guiltyVotes,
+            not_guilty_votes: result.notGuiltyVotes,
+            evidence_root: result.evidenceRoot,
+        })
+    }
+
+    /// Returns `(has_committed, has_revealed, vote)` for a juror on a dispute
+    pub async fn get_juror_vote(
+        &self,
+        dispute_id: U256,
+        juror: Address,
+    ) -> Result<(bool, bool, bool)> {
+        let contract =
+            ModerationGame::new(self.config.moderation_game_address.parse()?, &self.provider);
+
+        let result = contract.getJurorVote(dispute_id, juror).call().await?;
+
+        Ok((result.hasCommitted, result.hasRevealed, result.vote))
+    }
+
+    /// Returns the commitment a juror submitted for a dispute, or `None` if
+    /// they have not committed yet
+    pub async fn get_juror_commitment(
+        &self,
+        dispute_id: U256,
+        juror: Address,
+    ) -> Result<Option<B256>> {
+        let contract =
+            ModerationGame::new(self.config.moderation_game_address.parse()?, &self.provider);
+
+        let result = contract.jurorVotes(dispute_id, juror).call().await?;
+
+        Ok((!result.commitment.is_zero()).then_some(result.commitment))
+    }
+
     pub async fn get_stake_info(&self, user: Address) -> Result<StakeInfo> {
         let contract =
             StakingVault::new(self.config.staking_vault_address.parse()?, &self.provider);
 
-        // This would call the getStakeInfo method
-        // let result = contract.getStakeInfo(user).call().await?;
+        let result = contract.getStakeInfo(user).call().await?;
 
-        // Placeholder for now
         Ok(StakeInfo {
-            total_amount: U256::ZERO,
-            available: U256::ZERO,
-            locked: U256::ZERO,
-            staked_at: U256::ZERO,
-            stake_age: U256::ZERO,
+            total_amount: result.totalAmount,
+            available: result.available,
+            locked: result.locked,
+            staked_at: result.stakedAt,
+            stake_age: result.stakeAge,
         })
     }
 
@@ -84,40 +167,176 @@ impl ChainClient {
         let contract =
             ReputationSBT::new(self.config.reputation_sbt_address.parse()?, &self.provider);
 
-        // This would call the getReputation method
-        // let result = contract.getReputation(user).call().await?;
+        let result = contract.getReputation(user).call().await?;
 
-        Ok((U256::from(100), U256::ZERO))
+        Ok((result.karma, result.disputeRate))
     }
 
     pub async fn get_reputation_multiplier(&self, user: Address) -> Result<U256> {
         let contract =
             ReputationSBT::new(self.config.reputation_sbt_address.parse()?, &self.provider);
 
-        // This would call the getReputationMultiplier method
-        // let result = contract.getReputationMultiplier(user).call().await?;
-
-        Ok(U256::from(100))
+        Ok(contract.getReputationMultiplier(user).call().await?)
     }
 
     pub async fn get_pending_rewards(&self, user: Address) -> Result<U256> {
         let contract =
             StakingRewards::new(self.config.staking_rewards_address.parse()?, &self.provider);
 
-        // This would call the getPendingRewards method
-        // let result = contract.getPendingRewards(user).call().await?;
-
-        Ok(U256::ZERO)
+        Ok(contract.getPendingRewards(user).call().await?)
     }
 
     pub async fn is_eligible_staker(&self, user: Address) -> Result<bool> {
         let contract =
             StakingVault::new(self.config.staking_vault_address.parse()?, &self.provider);
 
-        // This would call the isEligibleStaker method
-        // let result = contract.isEligibleStaker(user).call().await?;
+        Ok(contract.isEligibleStaker(user).call().await?)
+    }
+
+    /// Reads every on-chain field of a user profile in a single Multicall3
+    /// `aggregate3` call. Only the stake read is required to succeed; the
+    /// others come back as `None` when their call reverts.
+    pub async fn get_on_chain_profile(&self, user: Address) -> Result<OnChainProfile> {
+        let vault = StakingVault::new(self.config.staking_vault_address.parse()?, &self.provider);
+        let sbt = ReputationSBT::new(self.config.reputation_sbt_address.parse()?, &self.provider);
+        let rewards =
+            StakingRewards::new(self.config.staking_rewards_address.parse()?, &self.provider);
+
+        let mut multicall = self
+            .provider
+            .multicall()
+            .add(vault.getStakeInfo(user))
+            .add_call(CallItemBuilder::new(sbt.getReputation(user)).with_failure_allowed())
+            .add_call(
+                CallItemBuilder::new(sbt.getReputationMultiplier(user)).with_failure_allowed(),
+            )
+            .add_call(CallItemBuilder::new(rewards.getPendingRewards(user)).with_failure_allowed())
+            .add_call(CallItemBuilder::new(vault.isEligibleStaker(user)).with_failure_allowed());
+
+        if let Some(address) = &self.config.multicall3_address {
+            multicall = multicall.address(address.parse()?);
+        }
+
+        let (stake, reputation, multiplier, pending_rewards, eligible) =
+            multicall.aggregate3().await?;
+
+        let stake = stake.map_err(|f| anyhow::anyhow!("getStakeInfo failed: {:?}", f))?;
+
+        Ok(OnChainProfile {
+            stake: StakeInfo {
+                total_amount: stake.totalAmount,
+                available: stake.available,
+                locked: stake.locked,
+                staked_at: stake.stakedAt,
+                stake_age: stake.stakeAge,
+            },
+            reputation: reputation.ok().map(|r| (r.karma, r.disputeRate)),
+            reputation_multiplier: multiplier.ok(),
+            pending_rewards: pending_rewards.ok(),
+            is_eligible_staker: eligible.ok(),
+        })
+    }
+
+    /// Reads whether `user` may vote and the weight their vote carries
+    pub async fn get_voting_power(&self, user: Address) -> Result<VotingPower> {
+        let vault = StakingVault::new(self.config.staking_vault_address.parse()?, &self.provider);
+        let sbt = ReputationSBT::new(self.config.reputation_sbt_address.parse()?, &self.provider);
+
+        let mut multicall = self
+            .provider
+            .multicall()
+            .add(vault.isEligibleStaker(user))
+            .add(sbt.hasSBT(user))
+            .add(sbt.getReputationMultiplier(user));
+
+        if let Some(address) = &self.config.multicall3_address {
+            multicall = multicall.address(address.parse()?);
+        }
+
+        let (is_eligible_staker, has_sbt, reputation_multiplier) = multicall.aggregate().await?;
+
+        Ok(VotingPower {
+            is_eligible_staker,
+            has_sbt,
+            reputation_multiplier,
+        })
+    }
+
+    /// Reads the `StakingRewards` epoch state together with the current block
+    /// timestamp, so the caller can tell whether `checkpoint()` would succeed.
+    pub async fn get_epoch_schedule(&self) -> Result<EpochSchedule> {
+        let rewards =
+            StakingRewards::new(self.config.staking_rewards_address.parse()?, &self.provider);
+
+        let mut multicall = self
+            .provider
+            .multicall()
+            .add(rewards.currentEpoch())
+            .add(rewards.lastEpochTime())
+            .add(rewards.EPOCH_DURATION())
+            .add(rewards.accumulatedRewards())
+            .add(rewards.MIN_DISTRIBUTION())
+            .get_current_block_timestamp();
+
+        if let Some(address) = &self.config.multicall3_address {
+            multicall = multicall.address(address.parse()?);
+        }
+
+        let (
+            current_epoch,
+            last_epoch_time,
+            epoch_duration,
+            accumulated_rewards,
+            min_distribution,
+            block_timestamp,
+        ) = multicall.aggregate().await?;
+
+        Ok(EpochSchedule {
+            current_epoch,
+            last_epoch_time,
+            epoch_duration,
+            accumulated_rewards,
+            min_distribution,
+            block_timestamp,
+        })
+    }
+
+    /// Reads `getUserEpochInfo` for each of `epoch_ids`, batched into
+    /// Multicall3 calls of up to `EPOCH_INFO_BATCH_SIZE` epochs
+    pub async fn get_user_epoch_infos(
+        &self,
+        user: Address,
+        epoch_ids: &[U256],
+    ) -> Result<Vec<UserEpochInfo>> {
+        let rewards =
+            StakingRewards::new(self.config.staking_rewards_address.parse()?, &self.provider);
+
+        let mut infos = Vec::with_capacity(epoch_ids.len());
+        for batch in epoch_ids.chunks(EPOCH_INFO_BATCH_SIZE) {
+            let mut multicall = self
+                .provider
+                .multicall()
+                .dynamic::<StakingRewards::getUserEpochInfoCall>()
+                .extend(batch.iter().map(|id| rewards.getUserEpochInfo(user, *id)));
+
+            if let Some(address) = &self.config.multicall3_address {
+                multicall = multicall.address(address.parse()?);
+            }
 
-        Ok(true)
+            infos.extend(
+                multicall
+                    .aggregate()
+                    .await?
+                    .into_iter()
+                    .map(|result| UserEpochInfo {
+                        shares: result.shares,
+                        claimed: result.claimed,
+                        reward: result.reward,
+                    }),
+            );
+        }
+
+        Ok(infos)
     }
 }
 
@@ -132,6 +351,18 @@ pub struct ContentInfo {
     pub status: u8,
 }
 
+pub struct DisputeInfo {
+    pub content_id: U256,
+    pub challenger: Address,
+    pub challenger_bond: U256,
+    pub status: u8,
+    pub commit_deadline: U256,
+    pub reveal_deadline: U256,
+    pub guilty_votes: U256,
+    pub not_guilty_votes: U256,
+    pub evidence_root: B256,
+}
+
 pub struct StakeInfo {
     pub total_amount: U256,
     pub available: U256,
@@ -139,3 +370,272 @@ pub struct StakeInfo {
     pub staked_at: U256,
     pub stake_age: U256,
 }
+
+pub struct OnChainProfile {
+    pub stake: StakeInfo,
+    pub reputation: Option<(U256, U256)>,
+    pub reputation_multiplier: Option<U256>,
+    pub pending_rewards: Option<U256>,
+    pub is_eligible_staker: Option<bool>,
+}
+
+pub struct VotingPower {
+    pub is_eligible_staker: bool,
+    pub has_sbt: bool,
+    /// Percent, from 50 (0.5x) to 200 (2.0x); 100 without an SBT
+    pub reputation_multiplier: U256,
+}
+
+impl VotingPower {
+    pub fn can_vote(&self) -> bool {
+        self.is_eligible_staker || self.has_sbt
+    }
+}
+
+pub struct EpochSchedule {
+    pub current_epoch: U256,
+    pub last_epoch_time: U256,
+    pub epoch_duration: U256,
+    pub accumulated_rewards: U256,
+    pub min_distribution: U256,
+    pub block_timestamp: U256,
+}
+
+impl EpochSchedule {
+    /// Whether `checkpoint()` would pass both of its `require`s right now
+    pub fn is_due(&self) -> bool {
+        self.block_timestamp >= self.last_epoch_time + self.epoch_duration
+            && self.accumulated_rewards >= self.min_distribution
+    }
+}
+
+pub struct UserEpochInfo {
+    pub shares: U256,
+    pub claimed: bool,
+    pub reward: U256,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::{
+        node_bindings::Anvil,
+        primitives::{address, b256},
+        providers::{bindings::IMulticall3, ext::AnvilApi},
+        sol_types::SolCall,
+    };
+
+    const STUB: Address = address!("00000000000000000000000000000000000c0de5");
+    const USER: Address = address!("00000000000000000000000000000000000a11ce");
+
+    /// Runtime code that answers every call with `data`: it copies the bytes
+    /// following its 15-byte prefix into memory and returns them.
+    fn returning_stub(data: &[u8]) -> Vec<u8> {
+        let [hi, lo] = (data.len() as u16).to_be_bytes();
+        let mut code = vec![
+            0x61, hi, lo, // PUSH2 len
+            0x61, 0x00, 0x0f, // PUSH2 15
+            0x60, 0x00, // PUSH1 0
+            0x39, // CODECOPY
+            0x61, hi, lo, // PUSH2 len
+            0x60, 0x00, // PUSH1 0
+            0xf3, // RETURN
+        ];
+        code.extend_from_slice(data);
+        code
+    }
+
+    fn test_config(rpc_url: String) -> Config {
+        let stub = format!("{:?}", STUB);
+        Config {
+            rpc_url,
+            chain_id: 31337,
+            mdt_token_address: stub.clone(),
+            staking_vault_address: stub.clone(),
+            content_registry_address: stub.clone(),
+            moderation_game_address: stub.clone(),
+            reputation_sbt_address: stub.clone(),
+            staking_rewards_address: stub.clone(),
+            treasury_address: stub.clone(),
+            multicall3_address: Some(stub),
+            ..Config::for_tests()
+        }
+    }
+
+    /// Spawns anvil with the stub deployed at every contract address
+    async fn client_returning(data: Vec<u8>) -> (alloy::node_bindings::AnvilInstance, ChainClient) {
+        let anvil = Anvil::new().try_spawn().expect("anvil must be on PATH");
+        let client = ChainClient::new(test_config(anvil.endpoint()))
+            .await
+            .unwrap();
+        client
+            .provider()
+            .anvil_set_code(STUB, returning_stub(&data).into())
+            .await
+            .unwrap();
+        (anvil, client)
+    }
+
+    #[tokio::test]
+    #[ignore = "requires anvil"]
+    async fn get_content_info_maps_get_content() {
+        let content_hash =
+            b256!("1111111111111111111111111111111111111111111111111111111111111111");
+        let data = ContentRegistry::getContentCall::abi_encode_returns(
+            &ContentRegistry::getContentReturn {
+                author: USER,
+                contentHash: content_hash,
+                uri: "ipfs://post".to_string(),
+                bond: U256::from(100),
+                publishedAt: U256::from(1_700_000_000u64),
+                lockUntil: U256::from(1_700_604_800u64),
+                status: 1,
+            },
+        );
+        let (_anvil, client) = client_returning(data).await;
+
+        let info = client.get_content_info(U256::from(7)).await.unwrap();
+
+        assert_eq!(info.author, USER);
+        assert_eq!(info.content_hash, content_hash);
+        assert_eq!(info.uri, "ipfs://post");
+        assert_eq!(info.bond, U256::from(100));
+        assert_eq!(info.published_at, U256::from(1_700_000_000u64));
+        assert_eq!(info.lock_until, U256::from(1_700_604_800u64));
+        assert_eq!(info.status, 1);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires anvil"]
+    async fn get_stake_info_maps_get_stake_info() {
+        let data =
+            StakingVault::getStakeInfoCall::abi_encode_returns(&StakingVault::getStakeInfoReturn {
+                totalAmount: U256::from(500),
+                available: U256::from(300),
+                locked: U256::from(200),
+                stakedAt: U256::from(1_700_000_000u64),
+                stakeAge: U256::from(86_400),
+            });
+        let (_anvil, client) = client_returning(data).await;
+
+        let info = client.get_stake_info(USER).await.unwrap();
+
+        assert_eq!(info.total_amount, U256::from(500));
+        assert_eq!(info.available, U256::from(300));
+        assert_eq!(info.locked, U256::from(200));
+        assert_eq!(info.staked_at, U256::from(1_700_000_000u64));
+        assert_eq!(info.stake_age, U256::from(86_400));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires anvil"]
+    async fn get_reputation_returns_karma_and_dispute_rate() {
+        let data = ReputationSBT::getReputationCall::abi_encode_returns(
+            &ReputationSBT::getReputationReturn {
+                karma: U256::from(250),
+                disputeRate: U256::from(12),
+            },
+        );
+        let (_anvil, client) = client_returning(data).await;
+
+        let (karma, dispute_rate) = client.get_reputation(USER).await.unwrap();
+
+        assert_eq!(karma, U256::from(250));
+        assert_eq!(dispute_rate, U256::from(12));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires anvil"]
+    async fn single_value_getters_decode_return_values() {
+        let data = ReputationSBT::getReputationMultiplierCall::abi_encode_returns(&U256::from(150));
+        let (_anvil, client) = client_returning(data).await;
+        assert_eq!(
+            client.get_reputation_multiplier(USER).await.unwrap(),
+            U256::from(150)
+        );
+        assert_eq!(
+            client.get_pending_rewards(USER).await.unwrap(),
+            U256::from(150)
+        );
+
+        let data = StakingVault::isEligibleStakerCall::abi_encode_returns(&true);
+        let (_anvil, client) = client_returning(data).await;
+        assert!(client.is_eligible_staker(USER).await.unwrap());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires anvil"]
+    async fn get_on_chain_profile_decodes_aggregate3_results() {
+        let stake =
+            StakingVault::getStakeInfoCall::abi_encode_returns(&StakingVault::getStakeInfoReturn {
+                totalAmount: U256::from(500),
+                available: U256::from(300),
+                locked: U256::from(200),
+                stakedAt: U256::ZERO,
+                stakeAge: U256::ZERO,
+            });
+        let reputation = ReputationSBT::getReputationCall::abi_encode_returns(
+            &ReputationSBT::getReputationReturn {
+                karma: U256::from(250),
+                disputeRate: U256::from(12),
+            },
+        );
+        let multiplier =
+            ReputationSBT::getReputationMultiplierCall::abi_encode_returns(&U256::from(150));
+        let eligible = StakingVault::isEligibleStakerCall::abi_encode_returns(&true);
+
+        let result = |success: bool, data: Vec<u8>| IMulticall3::Result {
+            success,
+            returnData: data.into(),
+        };
+        let data = IMulticall3::aggregate3Call::abi_encode_returns(&vec![
+            result(true, stake),
+            result(true, reputation),
+            result(true, multiplier),
+            // getPendingRewards reverted
+            result(false, Vec::new()),
+            result(true, eligible),
+        ]);
+        let (_anvil, client) = client_returning(data).await;
+
+        let profile = client.get_on_chain_profile(USER).await.unwrap();
+
+        assert_eq!(profile.stake.total_amount, U256::from(500));
+        assert_eq!(profile.stake.locked, U256::from(200));
+        assert_eq!(profile.reputation, Some((U256::from(250), U256::from(12))));
+        assert_eq!(profile.reputation_multiplier, Some(U256::from(150)));
+        assert_eq!(profile.pending_rewards, None);
+        assert_eq!(profile.is_eligible_staker, Some(true));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires anvil"]
+    async fn get_user_epoch_infos_decodes_aggregate_results() {
+        let info = |reward: u64, claimed: bool| {
+            StakingRewards::getUserEpochInfoCall::abi_encode_returns(
+                &StakingRewards::getUserEpochInfoReturn {
+                    shares: U256::from(10),
+                    claimed,
+                    reward: U256::from(reward),
+                },
+            )
+            .into()
+        };
+        let data = IMulticall3::aggregateCall::abi_encode_returns(&IMulticall3::aggregateReturn {
+            blockNumber: U256::from(1),
+            returnData: vec![info(40, false), info(60, true)],
+        });
+        let (_anvil, client) = client_returning(data).await;
+
+        let infos = client
+            .get_user_epoch_infos(USER, &[U256::from(1), U256::from(2)])
+            .await
+            .unwrap();
+
+        assert_eq!(infos.len(), 2);
+        assert_eq!(infos[0].reward, U256::from(40));
+        assert!(!infos[0].claimed);
+        assert_eq!(infos[1].reward, U256::from(60));
+        assert!(infos[1].claimed);
+    }
+}
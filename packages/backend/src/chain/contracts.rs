@@ -42,3 +42,10 @@ sol!(
     StakingRewards,
     "abis/StakingRewards.json"
 );
+
+sol!(
+    #[allow(missing_docs)]
+    #[sol(rpc)]
+    Treasury,
+    "abis/Treasury.json"
+);
@@ -1,22 +1,75 @@
 use alloy::{
-    primitives::Address,
+    primitives::{ruint::FromUintError, Address, U256},
     providers::{Provider, ProviderBuilder, WsConnect},
-    rpc::types::{BlockNumberOrTag, Filter, Log},
+    rpc::types::{Filter, Log},
+    sol_types::SolEventInterface,
 };
 use futures_util::StreamExt;
 use serde_json::json;
-use tracing::{error, info};
+use std::time::Duration;
+use tracing::{error, info, warn};
+use uuid::Uuid;
 
-use crate::{config::Config, db::Database};
+use crate::{
+    chain::{
+        contracts::{
+            ContentRegistry::ContentRegistryEvents, ModerationGame::ModerationGameEvents,
+            StakingRewards::StakingRewardsEvents, StakingVault::StakingVaultEvents,
+        },
+        ChainClient,
+    },
+    config::Config,
+    db::{Database, NotIndexed},
+    models::{ChainEvent, ChallengeReason, ContentStatus, Dispute},
+};
+
+/// Contracts whose logs are ingested by the listener
+#[derive(Debug, Clone, Copy)]
+enum WatchedContract {
+    ContentRegistry,
+    StakingVault,
+    ModerationGame,
+    StakingRewards,
+}
+
+impl WatchedContract {
+    const ALL: [WatchedContract; 4] = [
+        WatchedContract::ContentRegistry,
+        WatchedContract::StakingVault,
+        WatchedContract::ModerationGame,
+        WatchedContract::StakingRewards,
+    ];
+
+    fn name(&self) -> &'static str {
+        match self {
+            WatchedContract::ContentRegistry => "ContentRegistry",
+            WatchedContract::StakingVault => "StakingVault",
+            WatchedContract::ModerationGame => "ModerationGame",
+            WatchedContract::StakingRewards => "StakingRewards",
+        }
+    }
+}
+
+/// How many processed block hashes are kept for finding a reorg's common ancestor
+const REORG_HISTORY_BLOCKS: u64 = 1024;
+
+/// Delay before reconnecting a dropped WebSocket, doubled after each attempt
+const RECONNECT_BACKOFF_MIN: Duration = Duration::from_secs(1);
+const RECONNECT_BACKOFF_MAX: Duration = Duration::from_secs(60);
 
 pub struct EventListener {
     config: Config,
     db: Database,
+    chain_client: ChainClient,
 }
 
 impl EventListener {
-    pub fn new(config: Config, db: Database) -> Self {
-        Self { config, db }
+    pub fn new(config: Config, db: Database, chain_client: ChainClient) -> Self {
+        Self {
+            config,
+            db,
+            chain_client,
+        }
     }
 
     pub async fn start(&self) -> anyhow::Result<()> {
@@ -30,148 +83,826 @@ impl EventListener {
             self.config.rpc_url.replace("http", "ws")
         };
 
-        // Connect to WebSocket
-        match self.connect_and_listen(ws_url).await {
-            Ok(_) => info!("Event listener completed"),
-            Err(e) => {
-                error!("Event listener error: {}", e);
-                // Fall back to polling mode
-                self.polling_mode().await?;
-            }
-        }
+        let mut backoff = RECONNECT_BACKOFF_MIN;
+        let mut connected = false;
 
-        Ok(())
-    }
+        loop {
+            info!("Connecting to WebSocket: {}", ws_url);
 
-    async fn connect_and_listen(&self, ws_url: String) -> anyhow::Result<()> {
-        info!("Connecting to WebSocket: {}", ws_url);
+            match ProviderBuilder::new()
+                .connect_ws(WsConnect::new(ws_url.clone()))
+                .await
+            {
+                Ok(provider) => {
+                    connected = true;
+                    match self.listen(&provider).await {
+                        Ok(()) => {
+                            warn!("Event subscription closed, reconnecting");
+                            // The connection was healthy until it dropped
+                            backoff = RECONNECT_BACKOFF_MIN;
+                        }
+                        Err(e) => error!("Event listener error: {}, reconnecting", e),
+                    }
+                }
+                Err(e) if !connected => {
+                    error!("Failed to connect to WebSocket: {}", e);
+                    // Fall back to polling mode
+                    return self.polling_mode().await;
+                }
+                Err(e) => error!("Failed to reconnect to WebSocket: {}", e),
+            }
 
-        let ws = WsConnect::new(ws_url);
-        let provider = ProviderBuilder::new().connect_ws(ws).await?;
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(RECONNECT_BACKOFF_MAX);
+        }
+    }
 
+    /// Follows the subscriptions until one of them closes, which means the
+    /// WebSocket went away
+    async fn listen<P: Provider>(&self, provider: &P) -> anyhow::Result<()> {
         // Create filters for different contract events
-        let content_registry_address = self.config.content_registry_address.parse::<Address>()?;
-        let staking_vault_address = self.config.staking_vault_address.parse::<Address>()?;
-        let moderation_game_address = self.config.moderation_game_address.parse::<Address>()?;
-
-        // Content Registry events filter
-        let content_filter = Filter::new()
-            .address(content_registry_address)
-            .from_block(BlockNumberOrTag::Latest);
-
-        // Staking Vault events filter
-        let staking_filter = Filter::new()
-            .address(staking_vault_address)
-            .from_block(BlockNumberOrTag::Latest);
-
-        // Moderation Game events filter
-        let moderation_filter = Filter::new()
-            .address(moderation_game_address)
-            .from_block(BlockNumberOrTag::Latest);
-
-        // Subscribe to logs
+        let content_filter =
+            Filter::new().address(self.contract_address(WatchedContract::ContentRegistry)?);
+        let staking_filter =
+            Filter::new().address(self.contract_address(WatchedContract::StakingVault)?);
+        let moderation_filter =
+            Filter::new().address(self.contract_address(WatchedContract::ModerationGame)?);
+        let rewards_filter =
+            Filter::new().address(self.contract_address(WatchedContract::StakingRewards)?);
+
+        // Subscribe before backfilling so nothing emitted while catching up is missed
         let content_sub = provider.subscribe_logs(&content_filter).await?;
         let staking_sub = provider.subscribe_logs(&staking_filter).await?;
         let moderation_sub = provider.subscribe_logs(&moderation_filter).await?;
+        let rewards_sub = provider.subscribe_logs(&rewards_filter).await?;
 
         let mut content_stream = content_sub.into_stream();
         let mut staking_stream = staking_sub.into_stream();
         let mut moderation_stream = moderation_sub.into_stream();
+        let mut rewards_stream = rewards_sub.into_stream();
 
         info!("Subscribed to contract events");
 
-        // Listen for events
+        // Catch up on everything confirmed since the stored cursors
+        self.sync(provider).await?;
+        info!("Backfill complete");
+
+        // Subscriptions only signal that new logs exist; confirmed logs are
+        // always read back through `sync` so reorgs can be detected first
+        let mut interval = tokio::time::interval(Duration::from_secs(
+            self.config.listener_poll_interval_secs.max(1),
+        ));
+
         loop {
-            tokio::select! {
-                Some(log) = content_stream.next() => {
-                    self.handle_content_event(log).await;
+            let log = tokio::select! {
+                log = content_stream.next() => log,
+                log = staking_stream.next() => log,
+                log = moderation_stream.next() => log,
+                log = rewards_stream.next() => log,
+                _ = interval.tick() => {
+                    if let Err(e) = self.sync(provider).await {
+                        error!("Failed to sync contract logs: {}", e);
+                    }
+                    continue;
                 }
-                Some(log) = staking_stream.next() => {
-                    self.handle_staking_event(log).await;
+            };
+
+            // A closed subscription means the WebSocket went away
+            let Some(log) = log else {
+                break;
+            };
+
+            if log.removed {
+                if let Some(block_number) = log.block_number {
+                    warn!("Log removed by reorg at block {}", block_number);
+                    if let Err(e) = self.rollback(block_number).await {
+                        error!("Failed to roll back from block {}: {}", block_number, e);
+                    }
                 }
-                Some(log) = moderation_stream.next() => {
-                    self.handle_moderation_event(log).await;
+            }
+
+            if let Err(e) = self.sync(provider).await {
+                error!("Failed to sync contract logs: {}", e);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Rolls back any reorged blocks, then processes every watched contract up
+    /// to the head minus `listener_confirmations`.
+    async fn sync<P: Provider>(&self, provider: &P) -> anyhow::Result<()> {
+        let head = provider.get_block_number().await?;
+        let Some(safe_head) = head.checked_sub(self.config.listener_confirmations) else {
+            return Ok(());
+        };
+
+        self.detect_reorg(provider).await?;
+
+        // A contract stuck on a failing block must not hold back the others
+        for contract in WatchedContract::ALL {
+            if let Err(e) = self.backfill(provider, contract, safe_head).await {
+                error!("Failed to backfill {} logs: {:#}", contract.name(), e);
+            }
+        }
+
+        self.db
+            .prune_processed_blocks(safe_head.saturating_sub(REORG_HISTORY_BLOCKS))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Compares the stored hash of the latest processed block with the
+    /// canonical chain and, on mismatch, walks back to the common ancestor and
+    /// rolls back everything after it.
+    async fn detect_reorg<P: Provider>(&self, provider: &P) -> anyhow::Result<()> {
+        let processed = self
+            .db
+            .get_processed_blocks(REORG_HISTORY_BLOCKS as i64)
+            .await?;
+
+        let Some((latest_block, latest_hash)) = processed.first() else {
+            return Ok(());
+        };
+        if self.canonical_hash(provider, *latest_block).await? == Some(latest_hash.clone()) {
+            return Ok(());
+        }
+
+        // Without a common ancestor in the tracked window, discard the whole window
+        let mut fork_block = processed.last().map(|(number, _)| *number).unwrap_or(0);
+        for (number, hash) in processed.iter().skip(1) {
+            if self.canonical_hash(provider, *number).await? == Some(hash.clone()) {
+                fork_block = number + 1;
+                break;
+            }
+        }
+
+        warn!(
+            "Reorg detected at block {}, rolling back from block {}",
+            latest_block, fork_block
+        );
+        self.rollback(fork_block).await
+    }
+
+    /// Rolls back everything from `from_block` and re-reads the stake and
+    /// karma of the users named by the orphaned events
+    async fn rollback(&self, from_block: u64) -> anyhow::Result<()> {
+        for address in self.db.rollback_from_block(from_block).await? {
+            let Ok(user) = address.parse::<Address>() else {
+                continue;
+            };
+            if let Err(e) = self.refresh_user(user).await {
+                warn!("Failed to refresh {} after reorg: {}", address, e);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn canonical_hash<P: Provider>(
+        &self,
+        provider: &P,
+        block_number: u64,
+    ) -> anyhow::Result<Option<String>> {
+        let block = provider.get_block_by_number(block_number.into()).await?;
+        Ok(block.map(|b| format!("{:?}", b.header.hash)))
+    }
+
+    /// Replays logs for `contract` from its stored cursor up to `head` in
+    /// `listener_batch_size` block ranges, advancing the cursor after each range.
+    async fn backfill<P: Provider>(
+        &self,
+        provider: &P,
+        contract: WatchedContract,
+        head: u64,
+    ) -> anyhow::Result<()> {
+        let address = self.contract_address(contract)?;
+
+        let from_block = match self.db.get_block_cursor(&format!("{:?}", address)).await? {
+            Some(last_block) => last_block + 1,
+            None => match self.config.listener_start_block {
+                Some(start_block) => start_block,
+                None => {
+                    // Nothing stored and no start block configured: begin at the current head
+                    self.advance_cursor(contract, head).await?;
+                    return Ok(());
+                }
+            },
+        };
+
+        let batch_size = self.config.listener_batch_size.max(1);
+        let mut start = from_block;
+
+        while start <= head {
+            let end = (start + batch_size - 1).min(head);
+            info!(
+                "Backfilling {} logs from block {} to {}",
+                contract.name(),
+                start,
+                end
+            );
+
+            let filter = Filter::new()
+                .address(address)
+                .from_block(start)
+                .to_block(end);
+
+            for log in provider.get_logs(&filter).await? {
+                if let (Some(number), Some(hash)) = (log.block_number, log.block_hash) {
+                    self.db
+                        .record_processed_block(number, &format!("{:?}", hash))
+                        .await?;
                 }
-                else => break,
+                // Leave the cursor behind this range so a failed log is read again
+                self.handle_log(contract, log).await?;
             }
+
+            // Record the range end so the next sync can check it is still canonical
+            if let Some(hash) = self.canonical_hash(provider, end).await? {
+                self.db.record_processed_block(end, &hash).await?;
+            }
+
+            self.advance_cursor(contract, end).await?;
+            start = end + 1;
         }
 
         Ok(())
     }
 
+    async fn handle_log(&self, contract: WatchedContract, log: Log) -> anyhow::Result<()> {
+        match contract {
+            WatchedContract::ContentRegistry => self.handle_content_event(log).await,
+            WatchedContract::StakingVault => self.handle_staking_event(log).await,
+            WatchedContract::ModerationGame => self.handle_moderation_event(log).await,
+            WatchedContract::StakingRewards => self.handle_rewards_event(log).await,
+        }
+    }
+
+    async fn advance_cursor(&self, contract: WatchedContract, block: u64) -> anyhow::Result<()> {
+        let address = self.contract_address(contract)?;
+        self.db
+            .update_block_cursor(&format!("{:?}", address), contract.name(), block)
+            .await
+    }
+
+    fn contract_address(&self, contract: WatchedContract) -> anyhow::Result<Address> {
+        let address = match contract {
+            WatchedContract::ContentRegistry => &self.config.content_registry_address,
+            WatchedContract::StakingVault => &self.config.staking_vault_address,
+            WatchedContract::ModerationGame => &self.config.moderation_game_address,
+            WatchedContract::StakingRewards => &self.config.staking_rewards_address,
+        };
+
+        Ok(address.parse()?)
+    }
+
     async fn polling_mode(&self) -> anyhow::Result<()> {
         info!("Running in polling mode (WebSocket not available)");
 
-        // Keep the listener running with periodic checks
+        let provider = self.chain_client.provider();
+        let mut interval = tokio::time::interval(Duration::from_secs(
+            self.config.listener_poll_interval_secs.max(1),
+        ));
+
         loop {
-            tokio::time::sleep(tokio::time::Duration::from_secs(30)).await;
-            info!("Event listener heartbeat (polling mode)");
+            interval.tick().await;
+
+            if let Err(e) = self.sync(&provider).await {
+                error!("Failed to poll contract logs: {}", e);
+            }
         }
     }
 
-    async fn handle_content_event(&self, log: Log) {
+    async fn handle_content_event(&self, log: Log) -> anyhow::Result<()> {
         info!("Content Registry event: {:?}", log);
 
-        // Store raw event in database
-        if let Err(e) = self
-            .db
-            .track_chain_event(
-                log.block_number.unwrap_or_default(),
-                format!("{:?}", log.transaction_hash.unwrap_or_default()),
-                "ContentRegistry",
+        let event = match ContentRegistryEvents::decode_log(&log.inner) {
+            Ok(decoded) => decoded.data,
+            Err(e) => {
+                warn!("Failed to decode Content event: {}", e);
+                return Ok(());
+            }
+        };
+
+        let (event_type, data) = match &event {
+            ContentRegistryEvents::ContentPublished(e) => (
+                "ContentPublished",
                 json!({
-                    "topics": log.topics(),
-                    "data": format!("{:?}", log.data()),
-                    "address": format!("{:?}", log.address()),
+                    "contentId": e.contentId.to_string(),
+                    "author": format!("{:?}", e.author),
+                    "contentHash": format!("{:?}", e.contentHash),
                 }),
-            )
+            ),
+            ContentRegistryEvents::ContentChallenged(e) => (
+                "ContentChallenged",
+                json!({
+                    "contentId": e.contentId.to_string(),
+                    "challenger": format!("{:?}", e.challenger),
+                    "reason": e.reason,
+                }),
+            ),
+            ContentRegistryEvents::ChallengeResolved(e) => (
+                "ChallengeResolved",
+                json!({
+                    "contentId": e.contentId.to_string(),
+                    "guilty": e.guilty,
+                    "slashedAmount": e.slashedAmount.to_string(),
+                }),
+            ),
+            ContentRegistryEvents::BondWithdrawn(e) => (
+                "BondWithdrawn",
+                json!({
+                    "contentId": e.contentId.to_string(),
+                    "author": format!("{:?}", e.author),
+                    "amount": e.amount.to_string(),
+                }),
+            ),
+            // Role administration events are not relevant to content state
+            _ => return Ok(()),
+        };
+
+        let event_record = chain_event(&log, event_type, data);
+        let transaction_hash = event_record.transaction_hash.clone();
+        let log_index = event_record.log_index;
+
+        // A replayed log that was already applied needs no further work
+        if self
+            .db
+            .store_chain_event(event_record)
             .await
+            .map_err(|e| anyhow::anyhow!("Failed to track Content event: {}", e))?
         {
-            error!("Failed to track Content event: {}", e);
+            return Ok(());
+        }
+
+        let applied = self
+            .apply_content_event(
+                event,
+                log.block_number.unwrap_or_default(),
+                log.block_timestamp,
+            )
+            .await;
+
+        self.finish_event(event_type, &transaction_hash, log_index, applied)
+            .await
+    }
+
+    async fn apply_content_event(
+        &self,
+        event: ContentRegistryEvents,
+        block_number: u64,
+        block_timestamp: Option<u64>,
+    ) -> anyhow::Result<()> {
+        match event {
+            ContentRegistryEvents::ContentPublished(e) => {
+                // The event carries no timestamps, and both are fixed at publish
+                let info = self.chain_client.get_content_info(e.contentId).await?;
+
+                let bound = self
+                    .db
+                    .bind_published_content(
+                        e.contentId,
+                        format!("{:?}", e.author),
+                        format!("{:?}", e.contentHash),
+                        timestamp(info.published_at),
+                        timestamp(info.lock_until),
+                    )
+                    .await?;
+
+                if !bound {
+                    let content_id = i64::try_from(e.contentId)?;
+                    if self.db.get_content_by_chain_id(content_id).await?.is_some() {
+                        self.db
+                            .update_content_status(e.contentId, ContentStatus::Published)
+                            .await?;
+                    } else {
+                        warn!(
+                            "Content {} published by {:?} with hash {:?} matches none of their drafts; leaving it unbound",
+                            e.contentId, e.author, e.contentHash
+                        );
+                    }
+                }
+            }
+            ContentRegistryEvents::ContentChallenged(e) => {
+                // Dated by its block so a backfill keeps the challenge's real age
+                let challenged_at = match block_timestamp {
+                    Some(seconds) => seconds,
+                    None => self.chain_client.get_block_timestamp(block_number).await?,
+                };
+
+                self.db
+                    .create_challenge(
+                        e.contentId,
+                        format!("{:?}", e.challenger),
+                        ChallengeReason::try_from(e.reason)?,
+                        None,
+                        block_number,
+                        timestamp(U256::from(challenged_at)),
+                    )
+                    .await?;
+                self.db
+                    .update_content_status(e.contentId, ContentStatus::Challenged)
+                    .await?;
+            }
+            ContentRegistryEvents::ChallengeResolved(e) => {
+                self.db
+                    .resolve_challenge(e.contentId, e.guilty, e.slashedAmount, block_number)
+                    .await?;
+                self.db
+                    .update_content_status(e.contentId, ContentStatus::Resolved)
+                    .await?;
+            }
+            // Withdrawing a bond does not change the content status
+            _ => {}
         }
+
+        Ok(())
     }
 
-    async fn handle_staking_event(&self, log: Log) {
+    async fn handle_staking_event(&self, log: Log) -> anyhow::Result<()> {
         info!("Staking Vault event: {:?}", log);
 
         // Store raw event in database
-        if let Err(e) = self
-            .db
-            .track_chain_event(
-                log.block_number.unwrap_or_default(),
-                format!("{:?}", log.transaction_hash.unwrap_or_default()),
+        self.db
+            .store_chain_event(chain_event(
+                &log,
                 "StakingVault",
                 json!({
                     "topics": log.topics(),
                     "data": format!("{:?}", log.data()),
                     "address": format!("{:?}", log.address()),
                 }),
+            ))
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to track Staking event: {}", e))?;
+
+        // Stake changes are read back from the vault rather than summed up
+        let user = match StakingVaultEvents::decode_log(&log.inner).map(|decoded| decoded.data) {
+            Ok(StakingVaultEvents::Deposited(e)) => e.user,
+            Ok(StakingVaultEvents::Withdrawn(e)) => e.user,
+            Ok(StakingVaultEvents::Slashed(e)) => e.user,
+            Ok(StakingVaultEvents::BondReserved(e)) => e.user,
+            Ok(StakingVaultEvents::BondReleased(e)) => e.user,
+            _ => return Ok(()),
+        };
+
+        self.refresh_user(user).await
+    }
+
+    /// Copies a user's current stake and reputation from chain into `users`
+    async fn refresh_user(&self, user: Address) -> anyhow::Result<()> {
+        let profile = self.chain_client.get_on_chain_profile(user).await?;
+
+        self.db
+            .update_user_chain_state(
+                &format!("{:?}", user),
+                profile.stake.total_amount,
+                profile
+                    .reputation
+                    .map(|(karma, _)| karma.saturating_to::<i32>()),
+                profile
+                    .reputation_multiplier
+                    .map(|multiplier| multiplier.saturating_to::<i32>()),
             )
             .await
-        {
-            error!("Failed to track Staking event: {}", e);
-        }
     }
 
-    async fn handle_moderation_event(&self, log: Log) {
+    async fn handle_moderation_event(&self, log: Log) -> anyhow::Result<()> {
         info!("Moderation Game event: {:?}", log);
 
-        // Store raw event in database
-        if let Err(e) = self
+        let event = match ModerationGameEvents::decode_log(&log.inner) {
+            Ok(decoded) => decoded.data,
+            Err(e) => {
+                warn!("Failed to decode Moderation event: {}", e);
+                return Ok(());
+            }
+        };
+
+        let (event_type, data) = match &event {
+            ModerationGameEvents::DisputeInitialized(e) => (
+                "DisputeInitialized",
+                json!({
+                    "disputeId": e.disputeId.to_string(),
+                    "contentId": e.contentId.to_string(),
+                    "challenger": format!("{:?}", e.challenger),
+                }),
+            ),
+            ModerationGameEvents::JurySelected(e) => (
+                "JurySelected",
+                json!({
+                    "disputeId": e.disputeId.to_string(),
+                    "jurors": e.jurors.iter().map(|j| format!("{:?}", j)).collect::<Vec<_>>(),
+                }),
+            ),
+            ModerationGameEvents::VoteCommitted(e) => (
+                "VoteCommitted",
+                json!({
+                    "disputeId": e.disputeId.to_string(),
+                    "juror": format!("{:?}", e.juror),
+                }),
+            ),
+            ModerationGameEvents::VoteRevealed(e) => (
+                "VoteRevealed",
+                json!({
+                    "disputeId": e.disputeId.to_string(),
+                    "juror": format!("{:?}", e.juror),
+                    "vote": e.vote,
+                }),
+            ),
+            ModerationGameEvents::DisputeResolved(e) => (
+                "DisputeResolved",
+                json!({
+                    "disputeId": e.disputeId.to_string(),
+                    "guilty": e.guilty,
+                    "guiltyVotes": e.guiltyVotes.to_string(),
+                    "notGuiltyVotes": e.notGuiltyVotes.to_string(),
+                }),
+            ),
+            // Role administration events are not relevant to dispute state
+            _ => return Ok(()),
+        };
+
+        let event_record = chain_event(&log, event_type, data);
+        let transaction_hash = event_record.transaction_hash.clone();
+        let log_index = event_record.log_index;
+
+        // A replayed log that was already applied needs no further work
+        if self
             .db
-            .track_chain_event(
+            .store_chain_event(event_record)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to track Moderation event: {}", e))?
+        {
+            return Ok(());
+        }
+
+        let applied = self
+            .apply_moderation_event(
+                event,
                 log.block_number.unwrap_or_default(),
-                format!("{:?}", log.transaction_hash.unwrap_or_default()),
-                "ModerationGame",
+                &transaction_hash,
+            )
+            .await;
+
+        self.finish_event(event_type, &transaction_hash, log_index, applied)
+            .await
+    }
+
+    async fn apply_moderation_event(
+        &self,
+        event: ModerationGameEvents,
+        block_number: u64,
+        transaction_hash: &str,
+    ) -> anyhow::Result<()> {
+        match event {
+            ModerationGameEvents::DisputeInitialized(e) => {
+                // Deadlines are fixed at initialization but not part of the event
+                let info = self.chain_client.get_dispute(e.disputeId).await?;
+
+                self.db
+                    .create_dispute(Dispute {
+                        dispute_id: i64::try_from(e.disputeId)?,
+                        content_id: i64::try_from(e.contentId)?,
+                        challenger_address: format!("{:?}", e.challenger),
+                        status: "active".to_string(),
+                        commit_deadline: timestamp(info.commit_deadline),
+                        reveal_deadline: timestamp(info.reveal_deadline),
+                        guilty: None,
+                        guilty_votes: None,
+                        not_guilty_votes: None,
+                        block_number: block_number as i64,
+                        transaction_hash: transaction_hash.to_string(),
+                        resolved_block: None,
+                        resolved_transaction_hash: None,
+                        created_at: chrono::Utc::now(),
+                        updated_at: chrono::Utc::now(),
+                        resolved_at: None,
+                    })
+                    .await?;
+            }
+            ModerationGameEvents::DisputeResolved(e) => {
+                self.db
+                    .record_dispute_resolution(
+                        e.disputeId,
+                        e.guilty,
+                        e.guiltyVotes,
+                        e.notGuiltyVotes,
+                        block_number,
+                        transaction_hash,
+                    )
+                    .await?;
+            }
+            ModerationGameEvents::JurySelected(e) => {
+                let jurors: Vec<String> = e.jurors.iter().map(|j| format!("{:?}", j)).collect();
+                self.db
+                    .assign_jurors(e.disputeId, &jurors, block_number)
+                    .await?;
+            }
+            ModerationGameEvents::VoteCommitted(e) => {
+                self.db
+                    .record_juror_commit(e.disputeId, format!("{:?}", e.juror), block_number)
+                    .await?;
+            }
+            ModerationGameEvents::VoteRevealed(e) => {
+                self.db
+                    .record_juror_reveal(
+                        e.disputeId,
+                        format!("{:?}", e.juror),
+                        e.vote,
+                        block_number,
+                    )
+                    .await?;
+            }
+            // Role administration events are filtered out before this point
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    async fn handle_rewards_event(&self, log: Log) -> anyhow::Result<()> {
+        info!("Staking Rewards event: {:?}", log);
+
+        let event = match StakingRewardsEvents::decode_log(&log.inner) {
+            Ok(decoded) => decoded.data,
+            Err(e) => {
+                warn!("Failed to decode Rewards event: {}", e);
+                return Ok(());
+            }
+        };
+
+        let (event_type, data) = match &event {
+            StakingRewardsEvents::EpochFinalized(e) => (
+                "EpochFinalized",
                 json!({
-                    "topics": log.topics(),
-                    "data": format!("{:?}", log.data()),
-                    "address": format!("{:?}", log.address()),
+                    "epochId": e.epochId.to_string(),
+                    "totalRewards": e.totalRewards.to_string(),
+                    "totalShares": e.totalShares.to_string(),
                 }),
-            )
+            ),
+            StakingRewardsEvents::RewardsAccrued(e) => (
+                "RewardsAccrued",
+                json!({
+                    "amount": e.amount.to_string(),
+                    "totalAccumulated": e.totalAccumulated.to_string(),
+                }),
+            ),
+            StakingRewardsEvents::RewardsClaimed(e) => (
+                "RewardsClaimed",
+                json!({
+                    "user": format!("{:?}", e.user),
+                    "amount": e.amount.to_string(),
+                    "epochId": e.epochId.to_string(),
+                }),
+            ),
+            StakingRewardsEvents::UserRegistered(e) => (
+                "UserRegistered",
+                json!({
+                    "user": format!("{:?}", e.user),
+                    "shares": e.shares.to_string(),
+                }),
+            ),
+            // Role administration events are not relevant to rewards state
+            _ => return Ok(()),
+        };
+
+        let event_record = chain_event(&log, event_type, data);
+        let transaction_hash = event_record.transaction_hash.clone();
+        let log_index = event_record.log_index;
+
+        // A replayed log that was already applied needs no further work
+        if self
+            .db
+            .store_chain_event(event_record)
             .await
+            .map_err(|e| anyhow::anyhow!("Failed to track Rewards event: {}", e))?
         {
-            error!("Failed to track Moderation event: {}", e);
+            return Ok(());
         }
+
+        // Accruals and claims are read from the contract when needed
+        let applied = match event {
+            StakingRewardsEvents::EpochFinalized(e) => {
+                self.db
+                    .record_reward_epoch(
+                        e.epochId,
+                        e.totalRewards,
+                        e.totalShares,
+                        &transaction_hash,
+                        log.block_number.unwrap_or_default(),
+                    )
+                    .await
+            }
+            _ => Ok(()),
+        };
+
+        self.finish_event(event_type, &transaction_hash, log_index, applied)
+            .await
+    }
+
+    /// Marks an applied event processed. An event no retry can apply is
+    /// logged and left unprocessed in `chain_events` so the cursor moves past
+    /// it; any other failure is returned so its block is read again.
+    async fn finish_event(
+        &self,
+        event_type: &str,
+        transaction_hash: &str,
+        log_index: Option<i64>,
+        applied: anyhow::Result<()>,
+    ) -> anyhow::Result<()> {
+        match applied {
+            Ok(()) => {
+                self.db
+                    .mark_chain_event_processed(transaction_hash, log_index)
+                    .await
+            }
+            Err(e) if is_unappliable(&e) => {
+                error!(
+                    "Skipping {} event in {} (log {:?}): {:#}",
+                    event_type, transaction_hash, log_index, e
+                );
+                Ok(())
+            }
+            Err(e) => Err(e.context(format!("Failed to apply {} event", event_type))),
+        }
+    }
+}
+
+/// Whether `error` comes from the event itself rather than from the RPC or
+/// database, so retrying the log can never succeed
+fn is_unappliable(error: &anyhow::Error) -> bool {
+    error
+        .chain()
+        .any(|cause| cause.is::<NotIndexed>() || cause.is::<FromUintError<i64>>())
+}
+
+/// Builds the `chain_events` row for a log, keyed by its transaction hash and log index
+fn chain_event(log: &Log, event_type: &str, data: serde_json::Value) -> ChainEvent {
+    ChainEvent {
+        id: Uuid::new_v4(),
+        block_number: log.block_number.unwrap_or_default() as i64,
+        block_hash: log.block_hash.map(|hash| format!("{:?}", hash)),
+        transaction_hash: format!("{:?}", log.transaction_hash.unwrap_or_default()),
+        log_index: log.log_index.map(|index| index as i64),
+        event_type: event_type.to_string(),
+        contract_address: format!("{:?}", log.address()),
+        data,
+        processed: false,
+        created_at: chrono::Utc::now(),
+        processed_at: None,
+    }
+}
+
+/// Converts a contract `block.timestamp` value to a UTC datetime
+fn timestamp(seconds: U256) -> chrono::DateTime<chrono::Utc> {
+    chrono::DateTime::from_timestamp(seconds.saturating_to::<i64>(), 0).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::{primitives::B256, sol_types::SolEvent};
+
+    use crate::chain::contracts::ContentRegistry::ContentChallenged;
+
+    #[tokio::test]
+    #[ignore = "requires postgres at DATABASE_URL"]
+    async fn challenge_on_unindexed_content_is_skipped() {
+        let config = Config::for_tests();
+        let db = Database::new(&std::env::var("DATABASE_URL").unwrap())
+            .await
+            .unwrap();
+        let chain_client = ChainClient::new(config.clone()).await.unwrap();
+        let listener = EventListener::new(config, db.clone(), chain_client);
+
+        let event = ContentChallenged {
+            contentId: U256::from(u64::MAX),
+            challenger: Address::repeat_byte(0x11),
+            reason: 0,
+        };
+        let log = Log {
+            inner: alloy::primitives::Log {
+                address: Address::ZERO,
+                data: event.encode_log_data(),
+            },
+            block_number: Some(1),
+            block_timestamp: Some(1),
+            transaction_hash: Some(B256::from(U256::from(Uuid::new_v4().as_u128()))),
+            log_index: Some(0),
+            ..Default::default()
+        };
+
+        listener
+            .handle_log(WatchedContract::ContentRegistry, log.clone())
+            .await
+            .unwrap();
+
+        // The event stays unprocessed so it can be inspected and replayed
+        let processed = db
+            .store_chain_event(chain_event(&log, "ContentChallenged", json!({})))
+            .await
+            .unwrap();
+        assert!(!processed);
     }
 }
@@ -1,6 +1,8 @@
 pub mod client;
 pub mod contracts;
 pub mod listener;
+pub mod relayer;
 
 pub use client::ChainClient;
 pub use listener::EventListener;
+pub use relayer::{Relayer, RelayerCall};
@@ -0,0 +1,509 @@
+use alloy::{
+    consensus::TxEnvelope,
+    network::{EthereumWallet, TransactionBuilder},
+    primitives::{Address, Bytes, U256},
+    providers::{DynProvider, Provider, ProviderBuilder},
+    rpc::types::{TransactionReceipt, TransactionRequest},
+    signers::local::PrivateKeySigner,
+    sol_types::SolCall,
+};
+use anyhow::Result;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::chain::contracts::*;
+use crate::config::Config;
+use crate::db::Database;
+use crate::models::RelayerTransaction;
+
+/// Extra headroom added on top of `eth_estimateGas`, in percent
+const GAS_LIMIT_BUFFER_PERCENT: u64 = 20;
+
+/// Submitted transactions without a receipt after this long are replaced
+const RECEIPT_TIMEOUT_MINUTES: i64 = 10;
+
+/// Fee increase for a replacement, in percent; nodes require at least 10
+const FEE_BUMP_PERCENT: u128 = 15;
+
+/// Privileged contract calls the backend signer submits on behalf of workers
+#[derive(Debug, Clone)]
+pub enum RelayerCall {
+    ResolveContent {
+        content_id: U256,
+        guilty: bool,
+    },
+    FinalizeDispute {
+        dispute_id: U256,
+    },
+    CheckpointRewards,
+    DistributeTreasury {
+        recipient: Address,
+        amount: U256,
+        reason: String,
+    },
+}
+
+impl RelayerCall {
+    fn kind(&self) -> &'static str {
+        match self {
+            RelayerCall::ResolveContent { .. } => "ContentRegistry.resolve",
+            RelayerCall::FinalizeDispute { .. } => "ModerationGame.finalizeDispute",
+            RelayerCall::CheckpointRewards => "StakingRewards.checkpoint",
+            RelayerCall::DistributeTreasury { .. } => "Treasury.distribute",
+        }
+    }
+
+    fn target(&self, config: &Config) -> Result<Address> {
+        let address = match self {
+            RelayerCall::ResolveContent { .. } => &config.content_registry_address,
+            RelayerCall::FinalizeDispute { .. } => &config.moderation_game_address,
+            RelayerCall::CheckpointRewards => &config.staking_rewards_address,
+            RelayerCall::DistributeTreasury { .. } => &config.treasury_address,
+        };
+
+        Ok(address.parse()?)
+    }
+
+    fn calldata(&self) -> Vec<u8> {
+        match self {
+            RelayerCall::ResolveContent { content_id, guilty } => ContentRegistry::resolveCall {
+                contentId: *content_id,
+                guilty: *guilty,
+            }
+            .abi_encode(),
+            RelayerCall::FinalizeDispute { dispute_id } => ModerationGame::finalizeDisputeCall {
+                disputeId: *dispute_id,
+            }
+            .abi_encode(),
+            RelayerCall::CheckpointRewards => StakingRewards::checkpointCall {}.abi_encode(),
+            RelayerCall::DistributeTreasury {
+                recipient,
+                amount,
+                reason,
+            } => Treasury::distributeCall {
+                recipient: *recipient,
+                amount: *amount,
+                reason: reason.clone(),
+            }
+            .abi_encode(),
+        }
+    }
+}
+
+/// Signs and submits queued privileged calls. Calls are persisted in
+/// `relayer_transactions` so they survive restarts and can be retried.
+#[derive(Clone)]
+pub struct Relayer {
+    provider: DynProvider,
+    wallet: EthereumWallet,
+    address: Address,
+    db: Database,
+    config: Config,
+}
+
+impl Relayer {
+    /// Builds the relayer from `WALLET_PRIVATE_KEY`, or returns `None` when no
+    /// key is configured and the backend runs read-only.
+    pub async fn new(config: Config, db: Database) -> Result<Option<Self>> {
+        let Some(private_key) = &config.wallet_private_key else {
+            return Ok(None);
+        };
+
+        let signer: PrivateKeySigner = private_key.parse()?;
+        let address = signer.address();
+
+        let wallet = EthereumWallet::from(signer);
+        let provider = ProviderBuilder::new()
+            .wallet(wallet.clone())
+            .connect(&config.rpc_url)
+            .await?
+            .erased();
+
+        Ok(Some(Self {
+            provider,
+            wallet,
+            address,
+            db,
+            config,
+        }))
+    }
+
+    pub fn address(&self) -> Address {
+        self.address
+    }
+
+    /// Queues `call` for submission. Calls sharing a `dedupe_key` are only
+    /// queued once unless the earlier one failed; `None` is returned for the
+    /// duplicates.
+    pub async fn enqueue(
+        &self,
+        call: RelayerCall,
+        dedupe_key: Option<String>,
+    ) -> Result<Option<Uuid>> {
+        let target = call.target(&self.config)?;
+
+        self.db
+            .enqueue_relayer_transaction(
+                call.kind(),
+                &format!("{:?}", target),
+                &format!("0x{}", hex::encode(call.calldata())),
+                dedupe_key,
+            )
+            .await
+    }
+
+    /// Settles transactions left in `sending` by a previous run: those whose
+    /// signed hash the node knows are tracked as submitted, the rest are
+    /// requeued and keep their nonce.
+    pub async fn recover_interrupted(&self) -> Result<()> {
+        let lock = self.db.lock_relayer_nonces().await?;
+
+        for transaction in self.db.get_sending_relayer_transactions().await? {
+            if self.is_broadcast(&transaction).await? {
+                info!(
+                    "Relayer found {} ({}) broadcast before a restart",
+                    transaction.kind, transaction.id
+                );
+                self.db.mark_relayer_submitted(transaction.id).await?;
+            } else {
+                self.db.requeue_relayer_transaction(transaction.id).await?;
+            }
+        }
+
+        lock.commit().await?;
+
+        Ok(())
+    }
+
+    /// Submits the oldest queued transaction, returning whether one was found
+    pub async fn submit_next(&self) -> Result<bool> {
+        let Some(transaction) = self.db.claim_next_relayer_transaction().await? else {
+            return Ok(false);
+        };
+
+        if let Err(e) = self.submit(&transaction).await {
+            warn!(
+                "Relayer failed to submit {} ({}): {}",
+                transaction.kind, transaction.id, e
+            );
+            let abandoned_nonce = self
+                .db
+                .mark_relayer_attempt_failed(
+                    transaction.id,
+                    &e.to_string(),
+                    self.config.relayer_max_attempts,
+                )
+                .await?;
+
+            if let Some(nonce) = abandoned_nonce {
+                if let Err(e) = self.fill_nonce(&transaction, nonce).await {
+                    warn!(
+                        "Relayer failed to fill nonce {} of {} ({}): {}",
+                        nonce, transaction.kind, transaction.id, e
+                    );
+                }
+            }
+        }
+
+        Ok(true)
+    }
+
+    async fn submit(&self, transaction: &RelayerTransaction) -> Result<()> {
+        let to: Address = transaction.to_address.parse()?;
+        let input: Bytes = transaction.calldata.parse()?;
+
+        let request = TransactionRequest::default()
+            .with_from(self.address)
+            .with_to(to)
+            .with_input(input);
+
+        // Estimate before reserving a nonce so a reverting call never holds one
+        let estimate = self.provider.estimate_gas(request.clone()).await?;
+        let gas_limit = estimate + estimate * GAS_LIMIT_BUFFER_PERCENT / 100;
+        let fees = self.provider.estimate_eip1559_fees().await?;
+
+        let lock = self.db.lock_relayer_nonces().await?;
+
+        // A nonce reserved by an earlier attempt is kept, so a broadcast that
+        // did reach the mempool can only ever be replaced, never duplicated
+        let nonce = match transaction.nonce {
+            Some(nonce) => nonce as u64,
+            None => {
+                let pending = self
+                    .provider
+                    .get_transaction_count(self.address)
+                    .pending()
+                    .await?;
+                self.db
+                    .reserve_relayer_nonce(transaction.id, pending)
+                    .await?
+            }
+        };
+
+        let envelope = self
+            .sign(
+                request.with_nonce(nonce).with_gas_limit(gas_limit),
+                fees.max_fee_per_gas,
+                fees.max_priority_fee_per_gas,
+            )
+            .await?;
+        let tx_hash = format!("{:?}", envelope.tx_hash());
+
+        // Recorded before broadcasting so a crash in between can be settled on
+        // restart by looking the hash up instead of sending the call again
+        self.db
+            .record_relayer_signed(
+                transaction.id,
+                gas_limit,
+                i64::try_from(fees.max_fee_per_gas)?,
+                i64::try_from(fees.max_priority_fee_per_gas)?,
+                &tx_hash,
+            )
+            .await?;
+
+        if let Err(e) = self.send(envelope).await {
+            // A send that timed out may still have reached the node
+            let signed = self.db.get_relayer_transaction(transaction.id).await?;
+            if !self.is_broadcast(&signed).await? {
+                // The lock keeps later transactions from reserving past a fresh
+                // nonce, so handing it back here cannot leave a gap
+                if transaction.nonce.is_none() {
+                    self.db.release_relayer_nonce(transaction.id).await?;
+                }
+                return Err(e);
+            }
+        }
+
+        info!(
+            "Relayer submitted {} ({}) as {} with nonce {}",
+            transaction.kind, transaction.id, tx_hash, nonce
+        );
+
+        self.db.mark_relayer_submitted(transaction.id).await?;
+        lock.commit().await?;
+
+        Ok(())
+    }
+
+    /// Signs `request` locally so its hash is known before it is broadcast
+    async fn sign(
+        &self,
+        request: TransactionRequest,
+        max_fee_per_gas: u128,
+        max_priority_fee_per_gas: u128,
+    ) -> Result<TxEnvelope> {
+        let envelope = request
+            .with_chain_id(self.config.chain_id)
+            .with_max_fee_per_gas(max_fee_per_gas)
+            .with_max_priority_fee_per_gas(max_priority_fee_per_gas)
+            .build(&self.wallet)
+            .await?;
+
+        Ok(envelope)
+    }
+
+    async fn send(&self, envelope: TxEnvelope) -> Result<()> {
+        let _pending = self.provider.send_tx_envelope(envelope).await?;
+        Ok(())
+    }
+
+    /// Whether the node knows any signed hash of `transaction`, mined or pending
+    async fn is_broadcast(&self, transaction: &RelayerTransaction) -> Result<bool> {
+        for tx_hash in transaction
+            .tx_hash
+            .iter()
+            .chain(transaction.replaced_tx_hashes.iter())
+        {
+            if self
+                .provider
+                .get_transaction_by_hash(tx_hash.parse()?)
+                .await?
+                .is_some()
+            {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Sends a zero-value transfer to the signer at `nonce`, so a transaction
+    /// given up on does not leave a gap that holds back every later one
+    async fn fill_nonce(&self, transaction: &RelayerTransaction, nonce: u64) -> Result<()> {
+        let mined_nonce = self
+            .provider
+            .get_transaction_count(self.address)
+            .latest()
+            .await?;
+        if nonce < mined_nonce {
+            return Ok(());
+        }
+
+        // Outbid any earlier broadcast of the call still waiting at this nonce
+        let fees = self.provider.estimate_eip1559_fees().await?;
+        let request = TransactionRequest::default()
+            .with_from(self.address)
+            .with_to(self.address)
+            .with_value(U256::ZERO)
+            .with_nonce(nonce)
+            .with_gas_limit(21_000);
+
+        let envelope = self
+            .sign(
+                request,
+                fees.max_fee_per_gas
+                    .max(bump_fee(transaction.max_fee_per_gas)),
+                fees.max_priority_fee_per_gas
+                    .max(bump_fee(transaction.max_priority_fee_per_gas)),
+            )
+            .await?;
+        let tx_hash = format!("{:?}", envelope.tx_hash());
+        self.send(envelope).await?;
+
+        info!(
+            "Relayer filled nonce {} of {} ({}) with {}",
+            nonce, transaction.kind, transaction.id, tx_hash
+        );
+
+        Ok(())
+    }
+
+    /// Records receipts for submitted transactions and replaces the ones that
+    /// have been pending for too long.
+    pub async fn track_receipts(&self) -> Result<()> {
+        let transactions = self.db.get_submitted_relayer_transactions().await?;
+        if transactions.is_empty() {
+            return Ok(());
+        }
+
+        // Read before the receipts so a nonce mined in between still has one
+        let mined_nonce = self
+            .provider
+            .get_transaction_count(self.address)
+            .latest()
+            .await?;
+
+        for transaction in transactions {
+            if let Some(receipt) = self.find_receipt(&transaction).await? {
+                if !receipt.status() {
+                    warn!("Relayer transaction {} reverted", receipt.transaction_hash);
+                }
+                self.db
+                    .mark_relayer_mined(transaction.id, receipt.status(), receipt.block_number)
+                    .await?;
+                continue;
+            }
+
+            if transaction
+                .nonce
+                .is_some_and(|nonce| (nonce as u64) < mined_nonce)
+            {
+                warn!(
+                    "Relayer nonce {:?} of {} ({}) was used by another transaction",
+                    transaction.nonce, transaction.kind, transaction.id
+                );
+                self.db
+                    .mark_relayer_failed(transaction.id, "nonce used by another transaction")
+                    .await?;
+                continue;
+            }
+
+            let submitted_at = transaction.submitted_at.unwrap_or(transaction.updated_at);
+            if chrono::Utc::now() - submitted_at
+                > chrono::Duration::minutes(RECEIPT_TIMEOUT_MINUTES)
+            {
+                if let Err(e) = self.replace(&transaction).await {
+                    warn!(
+                        "Relayer failed to replace {} ({}): {}",
+                        transaction.kind, transaction.id, e
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Looks up the receipt of the latest broadcast or any it replaced
+    async fn find_receipt(
+        &self,
+        transaction: &RelayerTransaction,
+    ) -> Result<Option<TransactionReceipt>> {
+        for tx_hash in transaction
+            .tx_hash
+            .iter()
+            .chain(transaction.replaced_tx_hashes.iter().rev())
+        {
+            if let Some(receipt) = self
+                .provider
+                .get_transaction_receipt(tx_hash.parse()?)
+                .await?
+            {
+                return Ok(Some(receipt));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Rebroadcasts a stuck transaction with the same nonce and bumped fees
+    async fn replace(&self, transaction: &RelayerTransaction) -> Result<()> {
+        if transaction.attempts >= self.config.relayer_max_attempts {
+            // Resending under a new nonce could execute the call twice
+            warn!(
+                "Relayer transaction {} ({}) still pending after {} attempts",
+                transaction.kind, transaction.id, transaction.attempts
+            );
+            return Ok(());
+        }
+
+        let (Some(nonce), Some(gas_limit)) = (transaction.nonce, transaction.gas_limit) else {
+            return Err(anyhow::anyhow!("Submitted transaction has no nonce"));
+        };
+
+        // Nodes only accept a replacement that raises both fees
+        let fees = self.provider.estimate_eip1559_fees().await?;
+        let max_fee_per_gas = fees
+            .max_fee_per_gas
+            .max(bump_fee(transaction.max_fee_per_gas));
+        let max_priority_fee_per_gas = fees
+            .max_priority_fee_per_gas
+            .max(bump_fee(transaction.max_priority_fee_per_gas));
+
+        let request = TransactionRequest::default()
+            .with_from(self.address)
+            .with_to(transaction.to_address.parse::<Address>()?)
+            .with_input(transaction.calldata.parse::<Bytes>()?)
+            .with_nonce(nonce as u64)
+            .with_gas_limit(gas_limit as u64);
+
+        let envelope = self
+            .sign(request, max_fee_per_gas, max_priority_fee_per_gas)
+            .await?;
+        let tx_hash = format!("{:?}", envelope.tx_hash());
+
+        // Recorded first so the replacement's receipt is found even if the
+        // process dies right after broadcasting it
+        self.db
+            .mark_relayer_replaced(
+                transaction.id,
+                i64::try_from(max_fee_per_gas)?,
+                i64::try_from(max_priority_fee_per_gas)?,
+                &tx_hash,
+            )
+            .await?;
+        self.send(envelope).await?;
+
+        info!(
+            "Relayer replaced {} ({}) with {} at nonce {}",
+            transaction.kind, transaction.id, tx_hash, nonce
+        );
+
+        Ok(())
+    }
+}
+
+fn bump_fee(previous: Option<i64>) -> u128 {
+    let previous = previous.unwrap_or_default().max(0) as u128;
+    previous + previous * FEE_BUMP_PERCENT / 100 + 1
+}
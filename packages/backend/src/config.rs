@@ -18,6 +18,13 @@ pub struct Config {
     pub reputation_sbt_address: String,
     pub staking_rewards_address: String,
     pub treasury_address: String,
+    pub multicall3_address: Option<String>,
+
+    // Event listener
+    pub listener_start_block: Option<u64>,
+    pub listener_batch_size: u64,
+    pub listener_poll_interval_secs: u64,
+    pub listener_confirmations: u64,
 
     // Server
     pub backend_port: u16,
@@ -26,6 +33,23 @@ pub struct Config {
     // API
     pub api_secret_key: String,
 
+    // Auth
+    pub siwe_domain: String,
+    pub jwt_ttl_secs: u64,
+    pub salt_escrow_key: Option<String>,
+
+    // Relayer
+    pub wallet_private_key: Option<String>,
+    pub relayer_max_attempts: i32,
+    pub relayer_poll_interval_secs: u64,
+
+    // Workers
+    pub rewards_poll_interval_secs: u64,
+    pub dispute_poll_interval_secs: u64,
+    pub draft_ttl_secs: u64,
+    pub stats_refresh_interval_secs: u64,
+    pub ranking_refresh_interval_secs: u64,
+
     // ML Scoring
     pub scoring_service_url: Option<String>,
     pub scoring_api_key: Option<String>,
@@ -48,14 +72,113 @@ impl Config {
             reputation_sbt_address: env::var("REPUTATION_SBT_ADDRESS")?,
             staking_rewards_address: env::var("STAKING_REWARDS_ADDRESS")?,
             treasury_address: env::var("TREASURY_ADDRESS")?,
+            multicall3_address: env::var("MULTICALL3_ADDRESS")
+                .ok()
+                .filter(|v| !v.is_empty()),
+            listener_start_block: env::var("LISTENER_START_BLOCK")
+                .ok()
+                .and_then(|v| v.parse().ok()),
+            listener_batch_size: env::var("LISTENER_BATCH_SIZE")
+                .unwrap_or_else(|_| "1000".to_string())
+                .parse()
+                .unwrap_or(1000),
+            listener_poll_interval_secs: env::var("LISTENER_POLL_INTERVAL_SECS")
+                .unwrap_or_else(|_| "30".to_string())
+                .parse()
+                .unwrap_or(30),
+            listener_confirmations: env::var("LISTENER_CONFIRMATIONS")
+                .unwrap_or_else(|_| "3".to_string())
+                .parse()
+                .unwrap_or(3),
             backend_port: env::var("BACKEND_PORT")
                 .unwrap_or_else(|_| "8787".to_string())
                 .parse()
                 .unwrap_or(8787),
             backend_host: env::var("BACKEND_HOST").unwrap_or_else(|_| "0.0.0.0".to_string()),
             api_secret_key: env::var("API_SECRET_KEY")?,
+            // Sessions must be bound to a domain (EIP-4361), so there is no default
+            siwe_domain: env::var("SIWE_DOMAIN")
+                .ok()
+                .filter(|v| !v.is_empty())
+                .ok_or(env::VarError::NotPresent)?,
+            jwt_ttl_secs: env::var("JWT_TTL_SECS")
+                .unwrap_or_else(|_| "86400".to_string())
+                .parse()
+                .unwrap_or(86400),
+            stats_refresh_interval_secs: env::var("STATS_REFRESH_INTERVAL_SECS")
+                .unwrap_or_else(|_| "300".to_string())
+                .parse()
+                .unwrap_or(300),
+            ranking_refresh_interval_secs: env::var("RANKING_REFRESH_INTERVAL_SECS")
+                .unwrap_or_else(|_| "60".to_string())
+                .parse()
+                .unwrap_or(60),
+            salt_escrow_key: env::var("SALT_ESCROW_KEY").ok().filter(|v| !v.is_empty()),
+            wallet_private_key: env::var("WALLET_PRIVATE_KEY")
+                .ok()
+                .filter(|v| !v.is_empty()),
+            relayer_max_attempts: env::var("RELAYER_MAX_ATTEMPTS")
+                .unwrap_or_else(|_| "5".to_string())
+                .parse()
+                .unwrap_or(5),
+            relayer_poll_interval_secs: env::var("RELAYER_POLL_INTERVAL_SECS")
+                .unwrap_or_else(|_| "5".to_string())
+                .parse()
+                .unwrap_or(5),
+            rewards_poll_interval_secs: env::var("REWARDS_POLL_INTERVAL_SECS")
+                .unwrap_or_else(|_| "3600".to_string())
+                .parse()
+                .unwrap_or(3600),
+            dispute_poll_interval_secs: env::var("DISPUTE_POLL_INTERVAL_SECS")
+                .unwrap_or_else(|_| "60".to_string())
+                .parse()
+                .unwrap_or(60),
+            draft_ttl_secs: env::var("DRAFT_TTL_SECS")
+                .unwrap_or_else(|_| "86400".to_string())
+                .parse()
+                .unwrap_or(86400),
             scoring_service_url: env::var("SCORING_SERVICE_URL").ok(),
             scoring_api_key: env::var("SCORING_API_KEY").ok(),
         })
     }
 }
+
+#[cfg(test)]
+impl Config {
+    /// Placeholder configuration for unit tests; override what a test needs
+    pub fn for_tests() -> Self {
+        Config {
+            database_url: String::new(),
+            rpc_url: "http://localhost:8545".to_string(),
+            chain_id: 31337,
+            mdt_token_address: "0x0000000000000000000000000000000000000000".to_string(),
+            staking_vault_address: "0x0000000000000000000000000000000000000000".to_string(),
+            content_registry_address: "0x0000000000000000000000000000000000000000".to_string(),
+            moderation_game_address: "0x0000000000000000000000000000000000000000".to_string(),
+            reputation_sbt_address: "0x0000000000000000000000000000000000000000".to_string(),
+            staking_rewards_address: "0x0000000000000000000000000000000000000000".to_string(),
+            treasury_address: "0x0000000000000000000000000000000000000000".to_string(),
+            multicall3_address: None,
+            listener_start_block: None,
+            listener_batch_size: 1000,
+            listener_poll_interval_secs: 30,
+            listener_confirmations: 0,
+            backend_port: 8787,
+            backend_host: "127.0.0.1".to_string(),
+            api_secret_key: "test".to_string(),
+            siwe_domain: "localhost".to_string(),
+            jwt_ttl_secs: 3600,
+            salt_escrow_key: None,
+            wallet_private_key: None,
+            relayer_max_attempts: 5,
+            relayer_poll_interval_secs: 5,
+            rewards_poll_interval_secs: 3600,
+            dispute_poll_interval_secs: 60,
+            draft_ttl_secs: 86400,
+            stats_refresh_interval_secs: 300,
+            ranking_refresh_interval_secs: 60,
+            scoring_service_url: None,
+            scoring_api_key: None,
+        }
+    }
+}
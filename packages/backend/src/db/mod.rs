@@ -5,8 +5,18 @@ use std::time::Duration;
 use uuid::Uuid;
 
 use crate::models::*;
+use crate::pagination::Cursor;
 use anyhow::Result;
 
+/// `pg_advisory_xact_lock` key guarding relayer nonce assignment
+const RELAYER_NONCE_LOCK: i64 = 0x6d6f6e616464;
+
+/// A chain event referenced a row this database never indexed, e.g. a
+/// challenge on content published without a draft here
+#[derive(Debug, thiserror::Error)]
+#[error("{0} is not indexed")]
+pub struct NotIndexed(pub String);
+
 #[derive(Clone)]
 pub struct Database {
     pool: PgPool,
@@ -34,8 +44,8 @@ impl Database {
             r#"
             INSERT INTO contents (
                 id, content_id, author_address, content_hash, title, body,
-                uri, content_type, parent_id, community_id, bond_amount, status
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+                uri, content_type, parent_id, community_id, bond_amount, status, hash_version
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
             "#,
             id,
             content.content_id,
@@ -48,7 +58,8 @@ impl Database {
             content.parent_id,
             content.community_id,
             content.bond_amount,
-            content.status
+            content.status,
+            content.hash_version
         )
         .execute(&self.pool)
         .await?;
@@ -60,7 +71,10 @@ impl Database {
         let content = sqlx::query_as!(
             Content,
             r#"
-            SELECT * FROM contents WHERE id = $1
+            SELECT id, content_id, author_address, content_hash, title, body, uri, content_type,
+                   parent_id, community_id, bond_amount, status, published_at, lock_until,
+                   created_at, updated_at, hash_version
+            FROM contents WHERE id = $1
             "#,
             id
         )
@@ -74,7 +88,10 @@ impl Database {
         let content = sqlx::query_as!(
             Content,
             r#"
-            SELECT * FROM contents WHERE content_id = $1
+            SELECT id, content_id, author_address, content_hash, title, body, uri, content_type,
+                   parent_id, community_id, bond_amount, status, published_at, lock_until,
+                   created_at, updated_at, hash_version
+            FROM contents WHERE content_id = $1
             "#,
             content_id
         )
@@ -84,12 +101,86 @@ impl Database {
         Ok(content)
     }
 
+    /// Content stored under `content_hash`, preferring the published row over
+    /// any drafts with the same text
+    pub async fn get_content_by_hash(&self, content_hash: &str) -> Result<Option<Content>> {
+        let content = sqlx::query_as!(
+            Content,
+            r#"
+            SELECT id, content_id, author_address, content_hash, title, body, uri, content_type,
+                   parent_id, community_id, bond_amount, status, published_at, lock_until,
+                   created_at, updated_at, hash_version
+            FROM contents WHERE content_hash = $1
+            ORDER BY (content_id IS NOT NULL) DESC, created_at DESC
+            LIMIT 1
+            "#,
+            content_hash
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(content)
+    }
+
+    /// Binds the newest unpublished draft by `author` with `content_hash` to
+    /// its on-chain ID. Drafts by anyone else are never bound, so a copied hash
+    /// can't claim them. Returns false when no draft matched, e.g. because it
+    /// was already bound.
+    pub async fn bind_published_content(
+        &self,
+        content_id: U256,
+        author: String,
+        content_hash: String,
+        published_at: chrono::DateTime<chrono::Utc>,
+        lock_until: chrono::DateTime<chrono::Utc>,
+    ) -> Result<bool> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE contents
+            SET content_id = $1, published_at = $4, lock_until = $5, status = 'published'
+            WHERE id = (
+                SELECT id FROM contents
+                WHERE content_hash = $3 AND author_address = $2 AND content_id IS NULL
+                ORDER BY created_at DESC
+                LIMIT 1
+            )
+            "#,
+            i64::try_from(content_id)?,
+            author,
+            content_hash,
+            published_at,
+            lock_until
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Marks drafts that were never published before `created_before` as expired
+    pub async fn expire_drafts(
+        &self,
+        created_before: chrono::DateTime<chrono::Utc>,
+    ) -> Result<u64> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE contents SET status = 'expired'
+            WHERE content_id IS NULL AND status = 'pending' AND created_at < $1
+            "#,
+            created_before
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
     pub async fn update_content_status(
         &self,
         content_id: U256,
         status: ContentStatus,
     ) -> Result<()> {
-        let content_id = content_id.to::<i64>();
+        let content_id = i64::try_from(content_id)?;
         let status_str = status.to_string();
 
         sqlx::query!(
@@ -106,36 +197,181 @@ impl Database {
         Ok(())
     }
 
+    /// Published posts and comments by `author`, newest first, after `cursor`
+    /// or before it when it points backward
+    pub async fn get_contents_by_author(
+        &self,
+        author: &str,
+        cursor: Option<&Cursor>,
+        limit: i64,
+    ) -> Result<Vec<Content>> {
+        let contents = sqlx::query_as!(
+            Content,
+            r#"
+            SELECT id, content_id, author_address, content_hash, title, body, uri, content_type,
+                   parent_id, community_id, bond_amount, status, published_at, lock_until,
+                   created_at, updated_at, hash_version
+            FROM contents
+            WHERE author_address = $1 AND content_id IS NOT NULL
+              AND (
+                  $3::UUID IS NULL
+                  OR (NOT $4 AND (published_at, id) < ($2, $3))
+                  OR ($4 AND (published_at, id) > ($2, $3))
+              )
+            ORDER BY
+                CASE WHEN NOT $4 THEN published_at END DESC,
+                CASE WHEN NOT $4 THEN id END DESC,
+                published_at, id
+            LIMIT $5
+            "#,
+            author,
+            cursor.map(|c| c.published_at),
+            cursor.map(|c| c.id),
+            cursor.is_some_and(|c| c.backward),
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(contents)
+    }
+
+    /// Published content matching the web-search style `query`, best match
+    /// first. Content found guilty in a challenge is never returned.
+    pub async fn search_contents(
+        &self,
+        query: &str,
+        filter: &SearchFilter,
+        cursor: Option<&Cursor>,
+        limit: i64,
+    ) -> Result<Vec<SearchResult>> {
+        let results = sqlx::query_as!(
+            SearchResult,
+            r#"
+            SELECT
+                c.id, c.content_id AS "content_id!", c.author_address, c.content_type,
+                c.parent_id, c.community_id, c.status, c.published_at, c.title,
+                ts_headline('english', html_escape(c.title), q,
+                    'HighlightAll=true, StartSel=<mark>, StopSel=</mark>') AS "title_highlight!",
+                ts_headline('english', html_escape(c.body), q,
+                    'MaxFragments=2, MaxWords=30, MinWords=10, StartSel=<mark>, StopSel=</mark>')
+                    AS "snippet!",
+                ts_rank(c.search_vector, q)::FLOAT8 AS "rank!"
+            FROM contents c, websearch_to_tsquery('english', $1) q
+            WHERE c.search_vector @@ q AND c.content_id IS NOT NULL
+              AND ($2::VARCHAR IS NULL OR c.community_id = $2)
+              AND ($3::VARCHAR IS NULL OR c.author_address = $3)
+              AND ($4::VARCHAR IS NULL OR c.status = $4)
+              AND ($5::TIMESTAMPTZ IS NULL OR c.published_at >= $5)
+              AND ($6::TIMESTAMPTZ IS NULL OR c.published_at < $6)
+              AND NOT EXISTS (
+                  SELECT 1 FROM challenges ch WHERE ch.content_id = c.id AND ch.guilty IS TRUE
+              )
+              AND (
+                  $9::UUID IS NULL
+                  OR (NOT $10 AND (ts_rank(c.search_vector, q)::FLOAT8, c.published_at, c.id)
+                      < ($7, $8, $9))
+                  OR ($10 AND (ts_rank(c.search_vector, q)::FLOAT8, c.published_at, c.id)
+                      > ($7, $8, $9))
+              )
+            ORDER BY
+                CASE WHEN NOT $10 THEN ts_rank(c.search_vector, q) END DESC,
+                CASE WHEN NOT $10 THEN c.published_at END DESC,
+                CASE WHEN NOT $10 THEN c.id END DESC,
+                ts_rank(c.search_vector, q), c.published_at, c.id
+            LIMIT $11
+            "#,
+            query,
+            filter.community_id,
+            filter.author_address,
+            filter.status,
+            filter.published_after,
+            filter.published_before,
+            cursor.map(|c| c.key),
+            cursor.map(|c| c.published_at),
+            cursor.map(|c| c.id),
+            cursor.is_some_and(|c| c.backward),
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(results)
+    }
+
+    /// Posts in `community_id`, or in every community when `None`, ordered by
+    /// `sort`, using the scores kept in
+    /// `content_rankings`; posts not ranked yet sort last. Returns up to
+    /// `limit` posts after `cursor`, or before it when it points backward.
     pub async fn get_contents_by_community(
         &self,
-        community_id: &str,
+        community_id: Option<&str>,
+        sort: FeedSort,
+        published_since: Option<chrono::DateTime<chrono::Utc>>,
+        cursor: Option<&Cursor>,
         limit: i64,
-        offset: i64,
     ) -> Result<Vec<ContentWithStats>> {
         let contents = sqlx::query!(
             r#"
             SELECT 
-                c.*,
-                COUNT(DISTINCT v1.id) FILTER (WHERE v1.vote_type = 'upvote') as upvotes,
-                COUNT(DISTINCT v2.id) FILTER (WHERE v2.vote_type = 'downvote') as downvotes,
-                COUNT(DISTINCT c2.id) as comments_count,
+                c.id, c.content_id, c.author_address, c.content_hash, c.title, c.body, c.uri,
+                c.content_type, c.parent_id, c.community_id, c.bond_amount, c.status,
+                c.published_at, c.lock_until, c.created_at, c.updated_at, c.hash_version,
+                v.upvotes,
+                v.downvotes,
+                v.weighted_upvotes,
+                v.weighted_downvotes,
+                (SELECT COUNT(*) FROM contents c2 WHERE c2.parent_id = c.id) as comments_count,
+                f.sort_key AS "sort_key!",
                 u.address as user_address,
                 u.username,
                 u.karma,
                 u.reputation_multiplier
             FROM contents c
-            LEFT JOIN votes v1 ON c.id = v1.content_id AND v1.vote_type = 'upvote'
-            LEFT JOIN votes v2 ON c.id = v2.content_id AND v2.vote_type = 'downvote'
-            LEFT JOIN contents c2 ON c.id = c2.parent_id
+            CROSS JOIN LATERAL (
+                SELECT
+                    COUNT(*) FILTER (WHERE vote_type = 'upvote') AS upvotes,
+                    COUNT(*) FILTER (WHERE vote_type = 'downvote') AS downvotes,
+                    COALESCE(SUM(weight) FILTER (WHERE vote_type = 'upvote'), 0) AS weighted_upvotes,
+                    COALESCE(SUM(weight) FILTER (WHERE vote_type = 'downvote'), 0) AS weighted_downvotes
+                FROM votes
+                WHERE content_id = c.id
+            ) v
             LEFT JOIN users u ON c.author_address = u.address
-            WHERE c.community_id = $1 AND c.content_type = 'post'
-            GROUP BY c.id, u.address
-            ORDER BY c.published_at DESC
-            LIMIT $2 OFFSET $3
+            LEFT JOIN content_rankings r ON r.content_id = c.id
+            -- One numeric key per sort; unranked posts get a floor value
+            CROSS JOIN LATERAL (
+                SELECT COALESCE(CASE $2
+                    WHEN 'hot' THEN r.hot
+                    WHEN 'top' THEN r.score::FLOAT8
+                    WHEN 'rising' THEN r.rising
+                    WHEN 'controversial' THEN r.controversial
+                    ELSE 0
+                END, -1e300) AS sort_key
+            ) f
+            WHERE ($1::VARCHAR IS NULL OR c.community_id = $1)
+              AND c.content_type = 'post' AND c.content_id IS NOT NULL
+              AND ($3::TIMESTAMPTZ IS NULL OR c.published_at >= $3)
+              AND (
+                  $6::UUID IS NULL
+                  OR (NOT $7 AND (f.sort_key, c.published_at, c.id) < ($4, $5, $6))
+                  OR ($7 AND (f.sort_key, c.published_at, c.id) > ($4, $5, $6))
+              )
+            ORDER BY
+                CASE WHEN NOT $7 THEN f.sort_key END DESC,
+                CASE WHEN NOT $7 THEN c.published_at END DESC,
+                CASE WHEN NOT $7 THEN c.id END DESC,
+                f.sort_key, c.published_at, c.id
+            LIMIT $8
             "#,
             community_id,
-            limit,
-            offset
+            sort.as_str(),
+            published_since,
+            cursor.map(|c| c.key),
+            cursor.map(|c| c.published_at),
+            cursor.map(|c| c.id),
+            cursor.is_some_and(|c| c.backward),
+            limit
         )
         .fetch_all(&self.pool)
         .await?;
@@ -160,6 +396,7 @@ impl Database {
                 lock_until: row.lock_until,
                 created_at: row.created_at,
                 updated_at: row.updated_at,
+                hash_version: row.hash_version,
             };
 
             let author = if let Some(username) = row.username {
@@ -181,45 +418,221 @@ impl Database {
                 content,
                 upvotes: row.upvotes.unwrap_or(0),
                 downvotes: row.downvotes.unwrap_or(0),
+                weighted_upvotes: row.weighted_upvotes.unwrap_or(0),
+                weighted_downvotes: row.weighted_downvotes.unwrap_or(0),
                 comments_count: row.comments_count.unwrap_or(0),
                 author,
+                sort_key: row.sort_key,
             });
         }
 
         Ok(result)
     }
 
+    /// Recomputes `content_rankings` for every published post. Scores use
+    /// reputation-weighted votes: hot is Reddit's log-score plus publication
+    /// time, rising is the last six hours' net votes over post age, and
+    /// controversial rewards many votes that are evenly split.
+    pub async fn refresh_content_rankings(&self) -> Result<u64> {
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO content_rankings (content_id, score, hot, rising, controversial, computed_at)
+            SELECT
+                c.id,
+                v.up - v.down,
+                SIGN(v.up - v.down)::FLOAT8 * LOG(GREATEST(ABS(v.up - v.down) / 100.0, 1))::FLOAT8
+                    + EXTRACT(EPOCH FROM c.published_at)::FLOAT8 / 45000,
+                CASE WHEN c.published_at > NOW() - INTERVAL '1 day'
+                    THEN v.recent / 100.0
+                         / POWER(EXTRACT(EPOCH FROM NOW() - c.published_at)::FLOAT8 / 3600 + 2, 1.5)
+                END,
+                CASE WHEN v.up > 0 AND v.down > 0
+                    THEN POWER((v.up + v.down) / 100.0, LEAST(v.up, v.down)::FLOAT8 / GREATEST(v.up, v.down))
+                    ELSE 0
+                END,
+                NOW()
+            FROM contents c
+            CROSS JOIN LATERAL (
+                SELECT
+                    COALESCE(SUM(weight) FILTER (WHERE vote_type = 'upvote'), 0) AS up,
+                    COALESCE(SUM(weight) FILTER (WHERE vote_type = 'downvote'), 0) AS down,
+                    COALESCE(SUM(CASE WHEN vote_type = 'upvote' THEN weight ELSE -weight END)
+                        FILTER (WHERE created_at > NOW() - INTERVAL '6 hours'), 0) AS recent
+                FROM votes
+                WHERE content_id = c.id
+            ) v
+            WHERE c.content_type = 'post' AND c.content_id IS NOT NULL
+            ON CONFLICT (content_id) DO UPDATE SET
+                score = EXCLUDED.score,
+                hot = EXCLUDED.hot,
+                rising = EXCLUDED.rising,
+                controversial = EXCLUDED.controversial,
+                computed_at = EXCLUDED.computed_at
+            "#
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Comments below `root_id` down to `max_depth`, ranked among their
+    /// siblings by `sort`. Up to `limit` top-level comments are taken from
+    /// `cursor`, nearest first; deeper levels keep the first `replies_limit`
+    /// replies of each parent.
+    pub async fn get_comment_thread(
+        &self,
+        root_id: Uuid,
+        max_depth: i32,
+        sort: CommentSort,
+        cursor: Option<&Cursor>,
+        limit: i64,
+        replies_limit: i64,
+    ) -> Result<Vec<CommentRow>> {
+        let comments = sqlx::query_as!(
+            CommentRow,
+            r#"
+            WITH RECURSIVE thread AS (
+                SELECT c.id, c.parent_id, 1 AS depth
+                FROM contents c
+                WHERE c.parent_id = $1 AND c.content_type = 'comment' AND c.content_id IS NOT NULL
+                UNION ALL
+                SELECT c.id, c.parent_id, t.depth + 1
+                FROM contents c
+                JOIN thread t ON c.parent_id = t.id
+                WHERE t.depth < $2 AND c.content_type = 'comment' AND c.content_id IS NOT NULL
+            ),
+            tallies AS (
+                SELECT t.id, t.parent_id, t.depth,
+                       COUNT(v.id) FILTER (WHERE v.vote_type = 'upvote') AS upvotes,
+                       COUNT(v.id) FILTER (WHERE v.vote_type = 'downvote') AS downvotes,
+                       COALESCE(SUM(v.weight) FILTER (WHERE v.vote_type = 'upvote'), 0)
+                           AS weighted_upvotes,
+                       COALESCE(SUM(v.weight) FILTER (WHERE v.vote_type = 'downvote'), 0)
+                           AS weighted_downvotes
+                FROM thread t
+                LEFT JOIN votes v ON v.content_id = t.id
+                GROUP BY t.id, t.parent_id, t.depth
+            ),
+            keyed AS (
+                SELECT t.*, c.published_at,
+                       CASE
+                           WHEN $3 = 'top' THEN (t.weighted_upvotes - t.weighted_downvotes)::FLOAT8
+                           -- Many votes, evenly split
+                           WHEN $3 = 'controversial' AND t.upvotes > 0 AND t.downvotes > 0
+                               THEN POWER(t.weighted_upvotes + t.weighted_downvotes,
+                                          LEAST(t.weighted_upvotes, t.weighted_downvotes)::FLOAT8
+                                          / GREATEST(t.weighted_upvotes, t.weighted_downvotes))
+                           ELSE 0
+                       END AS sort_key
+                FROM tallies t
+                JOIN contents c ON c.id = t.id
+            ),
+            ranked AS (
+                SELECT k.*, ROW_NUMBER() OVER (
+                    PARTITION BY k.parent_id
+                    ORDER BY k.sort_key DESC, k.published_at DESC, k.id DESC
+                ) AS rank
+                FROM keyed k
+            ),
+            page AS (
+                SELECT r.id
+                FROM ranked r
+                WHERE r.depth = 1
+                  AND (
+                      $6::UUID IS NULL
+                      OR (NOT $7 AND (r.sort_key, r.published_at, r.id) < ($4, $5, $6))
+                      OR ($7 AND (r.sort_key, r.published_at, r.id) > ($4, $5, $6))
+                  )
+                ORDER BY CASE WHEN $7 THEN -r.rank ELSE r.rank END
+                LIMIT $8
+            )
+            SELECT
+                c.id, c.content_id AS "content_id!", c.parent_id AS "parent_id!",
+                c.author_address, c.body, c.status, c.published_at,
+                r.depth AS "depth!", r.sort_key AS "sort_key!",
+                r.upvotes AS "upvotes!", r.downvotes AS "downvotes!",
+                r.weighted_upvotes AS "weighted_upvotes!", r.weighted_downvotes AS "weighted_downvotes!",
+                (
+                    SELECT COUNT(*) FROM contents c2
+                    WHERE c2.parent_id = c.id AND c2.content_type = 'comment'
+                      AND c2.content_id IS NOT NULL
+                ) AS "replies_count!",
+                EXISTS(
+                    SELECT 1 FROM challenges ch WHERE ch.content_id = c.id AND ch.guilty IS TRUE
+                ) AS "slashed!"
+            FROM ranked r
+            JOIN contents c ON c.id = r.id
+            WHERE r.id IN (SELECT id FROM page)
+               OR (r.depth > 1 AND r.rank <= $9)
+            -- Top-level comments come nearest the cursor first
+            ORDER BY r.depth, CASE WHEN $7 AND r.depth = 1 THEN -r.rank ELSE r.rank END
+            "#,
+            root_id,
+            max_depth,
+            sort.as_str(),
+            cursor.map(|c| c.key),
+            cursor.map(|c| c.published_at),
+            cursor.map(|c| c.id),
+            cursor.is_some_and(|c| c.backward),
+            limit,
+            replies_limit
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(comments)
+    }
+
+    pub async fn count_comments(&self, parent_id: Uuid) -> Result<i64> {
+        let count = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) AS "count!" FROM contents
+            WHERE parent_id = $1 AND content_type = 'comment' AND content_id IS NOT NULL
+            "#,
+            parent_id
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(count)
+    }
+
     // Challenge operations
     pub async fn create_challenge(
         &self,
         content_id: U256,
         challenger_address: String,
-        reason: u8,
+        reason: ChallengeReason,
         evidence: Option<String>,
+        block_number: u64,
+        challenged_at: chrono::DateTime<chrono::Utc>,
     ) -> Result<Uuid> {
-        let content_id_i64 = content_id.to::<i64>();
+        let content_id_i64 = i64::try_from(content_id)?;
 
         // Get content UUID from content_id
         let content = self
             .get_content_by_chain_id(content_id_i64)
             .await?
-            .ok_or(anyhow::anyhow!("Content not found"))?;
+            .ok_or_else(|| NotIndexed(format!("Content {}", content_id)))?;
 
         let id = Uuid::new_v4();
-        let reason_str = format!("{}", reason);
 
         sqlx::query!(
             r#"
             INSERT INTO challenges (
-                id, content_id, challenger_address, reason, evidence, bond_amount
-            ) VALUES ($1, $2, $3, $4, $5, $6)
+                id, content_id, challenger_address, reason, evidence, bond_amount, block_number,
+                created_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
             "#,
             id,
             content.id,
             challenger_address,
-            reason_str,
+            reason as ChallengeReason,
             evidence,
-            BigDecimal::from_str("200000000000000000").unwrap() // 0.2 MDT
+            BigDecimal::from_str("200000000000000000").unwrap(), // 0.2 MDT
+            block_number as i64,
+            challenged_at
         )
         .execute(&self.pool)
         .await?;
@@ -227,22 +640,31 @@ impl Database {
         Ok(id)
     }
 
-    pub async fn resolve_challenge(&self, content_id: U256, guilty: bool) -> Result<()> {
-        let content_id_i64 = content_id.to::<i64>();
+    pub async fn resolve_challenge(
+        &self,
+        content_id: U256,
+        guilty: bool,
+        slashed_amount: U256,
+        block_number: u64,
+    ) -> Result<()> {
+        let content_id_i64 = i64::try_from(content_id)?;
 
         let content = self
             .get_content_by_chain_id(content_id_i64)
             .await?
-            .ok_or(anyhow::anyhow!("Content not found"))?;
+            .ok_or_else(|| NotIndexed(format!("Content {}", content_id)))?;
 
         sqlx::query!(
             r#"
             UPDATE challenges 
-            SET resolved = true, guilty = $1, resolved_at = NOW()
+            SET resolved = true, guilty = $1, resolved_at = NOW(), resolved_block = $3,
+                slashed_amount = $4
             WHERE content_id = $2 AND resolved = false
             "#,
             guilty,
-            content.id
+            content.id,
+            block_number as i64,
+            BigDecimal::from_str(&slashed_amount.to_string())?
         )
         .execute(&self.pool)
         .await?;
@@ -250,6 +672,78 @@ impl Database {
         Ok(())
     }
 
+    /// Challenges matching every filter that is set, newest first. A
+    /// challenge and its dispute are created in the same transaction, so they
+    /// are linked by content, challenger and block.
+    pub async fn list_challenges(
+        &self,
+        content_id: Option<Uuid>,
+        resolved: Option<bool>,
+        challenger: Option<String>,
+        reason: Option<ChallengeReason>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<ChallengeSummary>> {
+        let challenges = sqlx::query_as!(
+            ChallengeSummary,
+            r#"
+            SELECT c.id, c.content_id, ct.content_id AS "chain_content_id!", c.challenger_address,
+                   c.reason AS "reason: ChallengeReason", c.evidence, c.bond_amount, c.resolved AS "resolved!", c.guilty,
+                   c.slashed_amount, c.created_at, c.resolved_at,
+                   d.dispute_id AS "dispute_id?", d.status AS "dispute_status?",
+                   d.reveal_deadline AS "reveal_deadline?", d.guilty_votes, d.not_guilty_votes
+            FROM challenges c
+            JOIN contents ct ON ct.id = c.content_id
+            LEFT JOIN disputes d
+                ON d.content_id = ct.content_id
+               AND d.challenger_address = c.challenger_address
+               AND d.block_number = c.block_number
+            WHERE ($1::UUID IS NULL OR c.content_id = $1)
+              AND ($2::BOOLEAN IS NULL OR c.resolved = $2)
+              AND ($3::VARCHAR IS NULL OR c.challenger_address = $3)
+              AND ($4::challenge_reason IS NULL OR c.reason = $4)
+            ORDER BY c.created_at DESC
+            LIMIT $5 OFFSET $6
+            "#,
+            content_id,
+            resolved,
+            challenger,
+            reason as Option<ChallengeReason>,
+            limit,
+            offset
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(challenges)
+    }
+
+    /// The challenge that opened an on-chain dispute
+    pub async fn get_dispute_challenge(&self, dispute_id: i64) -> Result<Option<ChallengeSummary>> {
+        let challenge = sqlx::query_as!(
+            ChallengeSummary,
+            r#"
+            SELECT c.id, c.content_id, ct.content_id AS "chain_content_id!", c.challenger_address,
+                   c.reason AS "reason: ChallengeReason", c.evidence, c.bond_amount, c.resolved AS "resolved!", c.guilty,
+                   c.slashed_amount, c.created_at, c.resolved_at,
+                   d.dispute_id AS "dispute_id?", d.status AS "dispute_status?",
+                   d.reveal_deadline AS "reveal_deadline?", d.guilty_votes, d.not_guilty_votes
+            FROM disputes d
+            JOIN contents ct ON ct.content_id = d.content_id
+            JOIN challenges c
+                ON c.content_id = ct.id
+               AND c.challenger_address = d.challenger_address
+               AND c.block_number = d.block_number
+            WHERE d.dispute_id = $1
+            "#,
+            dispute_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(challenge)
+    }
+
     // User operations
     pub async fn create_or_update_user(&self, user: User) -> Result<()> {
         sqlx::query!(
@@ -294,26 +788,135 @@ impl Database {
         Ok(())
     }
 
+    /// Stores a user's on-chain stake and reputation; reputation values that
+    /// could not be read keep their previous value.
+    pub async fn update_user_chain_state(
+        &self,
+        address: &str,
+        total_stake: U256,
+        karma: Option<i32>,
+        reputation_multiplier: Option<i32>,
+    ) -> Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO users (address, total_stake, karma, reputation_multiplier)
+            VALUES ($1, $2, COALESCE($3, 100), COALESCE($4, 100))
+            ON CONFLICT (address) DO UPDATE SET
+                total_stake = EXCLUDED.total_stake,
+                karma = COALESCE($3, users.karma),
+                reputation_multiplier = COALESCE($4, users.reputation_multiplier),
+                updated_at = NOW()
+            "#,
+            address,
+            BigDecimal::from_str(&total_stake.to_string())?,
+            karma,
+            reputation_multiplier
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn update_username(&self, address: &str, username: Option<String>) -> Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO users (address, username)
+            VALUES ($1, $2)
+            ON CONFLICT (address) DO UPDATE SET
+                username = EXCLUDED.username,
+                updated_at = NOW()
+            "#,
+            address,
+            username
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_username(&self, address: &str) -> Result<Option<String>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT username FROM users WHERE address = $1
+            "#,
+            address
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.and_then(|r| r.username))
+    }
+
+    // Auth operations
+    pub async fn create_auth_nonce(
+        &self,
+        nonce: &str,
+        expires_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<()> {
+        // Drop expired nonces so the table stays small
+        sqlx::query!(
+            r#"
+            DELETE FROM auth_nonces WHERE expires_at < NOW()
+            "#
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO auth_nonces (nonce, expires_at)
+            VALUES ($1, $2)
+            "#,
+            nonce,
+            expires_at
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Deletes the nonce and returns whether it existed and had not expired
+    pub async fn consume_auth_nonce(&self, nonce: &str) -> Result<bool> {
+        let row = sqlx::query!(
+            r#"
+            DELETE FROM auth_nonces WHERE nonce = $1 AND expires_at > NOW()
+            RETURNING nonce
+            "#,
+            nonce
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.is_some())
+    }
+
     // Vote operations
     pub async fn create_vote(
         &self,
         content_id: Uuid,
         voter_address: String,
-        vote_type: String,
+        vote_type: VoteType,
+        weight: i32,
     ) -> Result<()> {
         let id = Uuid::new_v4();
 
+        // A changed vote is reweighted with the voter's current reputation
         sqlx::query!(
             r#"
-            INSERT INTO votes (id, content_id, voter_address, vote_type)
-            VALUES ($1, $2, $3, $4)
+            INSERT INTO votes (id, content_id, voter_address, vote_type, weight)
+            VALUES ($1, $2, $3, $4, $5)
             ON CONFLICT (content_id, voter_address) DO UPDATE SET
-                vote_type = EXCLUDED.vote_type
+                vote_type = EXCLUDED.vote_type,
+                weight = EXCLUDED.weight
             "#,
             id,
             content_id,
             voter_address,
-            vote_type
+            vote_type.as_str(),
+            weight
         )
         .execute(&self.pool)
         .await?;
@@ -341,27 +944,52 @@ impl Database {
     }
 
     // Chain event operations
-    pub async fn track_chain_event(
+    /// Upserts an event keyed by (transaction_hash, log_index) and returns
+    /// whether it had already been applied, so replayed logs are skipped.
+    pub async fn store_chain_event(&self, event: ChainEvent) -> Result<bool> {
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO chain_events (
+                id, block_number, block_hash, transaction_hash, log_index, event_type,
+                contract_address, data, processed, processed_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            ON CONFLICT (transaction_hash, log_index) DO UPDATE SET
+                block_number = EXCLUDED.block_number,
+                block_hash = EXCLUDED.block_hash,
+                event_type = EXCLUDED.event_type,
+                contract_address = EXCLUDED.contract_address,
+                data = EXCLUDED.data
+            RETURNING processed
+            "#,
+            event.id,
+            event.block_number,
+            event.block_hash,
+            event.transaction_hash,
+            event.log_index,
+            event.event_type,
+            event.contract_address,
+            event.data,
+            event.processed,
+            event.processed_at
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.processed.unwrap_or(false))
+    }
+
+    pub async fn mark_chain_event_processed(
         &self,
-        block_number: u64,
-        transaction_hash: String,
-        event_type: &str,
-        data: serde_json::Value,
+        transaction_hash: &str,
+        log_index: Option<i64>,
     ) -> Result<()> {
         sqlx::query!(
             r#"
-            INSERT INTO chain_events (
-                id, block_number, transaction_hash, event_type, 
-                contract_address, data, processed
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7)
+            UPDATE chain_events SET processed = true, processed_at = NOW()
+            WHERE transaction_hash = $1 AND log_index IS NOT DISTINCT FROM $2
             "#,
-            Uuid::new_v4(),
-            block_number as i64,
             transaction_hash,
-            event_type,
-            "0x0000000000000000000000000000000000000000", // placeholder
-            data,
-            false
+            log_index
         )
         .execute(&self.pool)
         .await?;
@@ -369,22 +997,37 @@ impl Database {
         Ok(())
     }
 
-    pub async fn store_chain_event(&self, event: ChainEvent) -> Result<()> {
+    // Block cursor operations
+    pub async fn get_block_cursor(&self, contract_address: &str) -> Result<Option<u64>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT last_block FROM block_cursors WHERE contract_address = $1
+            "#,
+            contract_address
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|r| r.last_block as u64))
+    }
+
+    pub async fn update_block_cursor(
+        &self,
+        contract_address: &str,
+        contract_name: &str,
+        last_block: u64,
+    ) -> Result<()> {
         sqlx::query!(
             r#"
-            INSERT INTO chain_events (
-                id, block_number, transaction_hash, event_type, 
-                contract_address, data, processed, processed_at
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            INSERT INTO block_cursors (contract_address, contract_name, last_block)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (contract_address) DO UPDATE SET
+                last_block = GREATEST(block_cursors.last_block, EXCLUDED.last_block),
+                updated_at = NOW()
             "#,
-            event.id,
-            event.block_number,
-            event.transaction_hash,
-            event.event_type,
-            event.contract_address,
-            event.data,
-            event.processed,
-            event.processed_at
+            contract_address,
+            contract_name,
+            last_block as i64
         )
         .execute(&self.pool)
         .await?;
@@ -392,51 +1035,823 @@ impl Database {
         Ok(())
     }
 
-    pub async fn record_slashing(
-        &self,
-        user_address: String,
-        amount: U256,
-        reason: String,
-    ) -> Result<()> {
-        // Store slashing event in chain_events table
-        let event = ChainEvent {
-            id: Uuid::new_v4(),
-            block_number: 0,
-            transaction_hash: "".to_string(),
-            event_type: "Slashed".to_string(),
-            contract_address: "".to_string(),
-            data: serde_json::json!({
-                "user": user_address,
-                "amount": amount.to_string(),
-                "reason": reason,
-            }),
-            processed: true,
-            created_at: chrono::Utc::now(),
-            processed_at: Some(chrono::Utc::now()),
-        };
+    // Reorg tracking operations
+    pub async fn record_processed_block(&self, block_number: u64, block_hash: &str) -> Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO processed_blocks (block_number, block_hash)
+            VALUES ($1, $2)
+            ON CONFLICT (block_number) DO UPDATE SET block_hash = EXCLUDED.block_hash
+            "#,
+            block_number as i64,
+            block_hash
+        )
+        .execute(&self.pool)
+        .await?;
 
-        self.store_chain_event(event).await?;
         Ok(())
     }
 
-    pub async fn record_dispute_resolution(
-        &self,
-        dispute_id: U256,
-        guilty: bool,
-        guilty_votes: U256,
-        not_guilty_votes: U256,
+    /// Most recent processed blocks first, as (block_number, block_hash)
+    pub async fn get_processed_blocks(&self, limit: i64) -> Result<Vec<(u64, String)>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT block_number, block_hash FROM processed_blocks
+            ORDER BY block_number DESC
+            LIMIT $1
+            "#,
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| (r.block_number as u64, r.block_hash))
+            .collect())
+    }
+
+    pub async fn prune_processed_blocks(&self, before_block: u64) -> Result<()> {
+        sqlx::query!(
+            r#"
+            DELETE FROM processed_blocks WHERE block_number < $1
+            "#,
+            before_block as i64
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Undoes everything derived from `from_block` onwards: orphaned events and
+    /// challenges are removed, resolutions reopened, content status recomputed
+    /// from the surviving events, and cursors rewound so the blocks are re-read.
+    /// Returns the addresses named by the orphaned events, whose stake and
+    /// karma in `users` must be read again from chain.
+    pub async fn rollback_from_block(&self, from_block: u64) -> Result<Vec<String>> {
+        let from_block = from_block as i64;
+        let mut tx = self.pool.begin().await?;
+
+        let affected = sqlx::query!(
+            r#"
+            SELECT DISTINCT (data->>'contentId')::BIGINT AS "content_id!"
+            FROM chain_events
+            WHERE block_number >= $1
+              AND event_type IN ('ContentPublished', 'ContentChallenged', 'ChallengeResolved')
+            "#,
+            from_block
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+        let affected: Vec<i64> = affected.into_iter().map(|r| r.content_id).collect();
+
+        // Raw StakingVault events name their user in the first indexed topic;
+        // role events carry a role hash there, which the zero padding rules out
+        let users = sqlx::query!(
+            r#"
+            SELECT DISTINCT address AS "address!" FROM (
+                SELECT '0x' || substr(data->'topics'->>1, 27) AS address
+                FROM chain_events
+                WHERE block_number >= $1 AND event_type = 'StakingVault'
+                  AND data->'topics'->>1 LIKE '0x000000000000000000000000%'
+                UNION
+                SELECT data->>field
+                FROM chain_events, unnest(ARRAY['author', 'challenger', 'juror']) AS field
+                WHERE block_number >= $1
+            ) named
+            WHERE address IS NOT NULL
+            "#,
+            from_block
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM challenges WHERE block_number >= $1
+            "#,
+            from_block
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            UPDATE challenges
+            SET resolved = false, guilty = NULL, resolved_at = NULL, resolved_block = NULL,
+                slashed_amount = NULL
+            WHERE resolved_block >= $1
+            "#,
+            from_block
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM juror_assignments WHERE block_number >= $1
+            "#,
+            from_block
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            UPDATE juror_assignments SET revealed = false, vote = NULL, revealed_block = NULL
+            WHERE revealed_block >= $1
+            "#,
+            from_block
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            UPDATE juror_assignments SET committed = false, committed_block = NULL
+            WHERE committed_block >= $1
+            "#,
+            from_block
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM disputes WHERE block_number >= $1
+            "#,
+            from_block
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            UPDATE disputes
+            SET status = 'active', guilty = NULL, guilty_votes = NULL, not_guilty_votes = NULL,
+                resolved_block = NULL, resolved_transaction_hash = NULL, resolved_at = NULL
+            WHERE resolved_block >= $1
+            "#,
+            from_block
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM reward_epochs WHERE block_number >= $1
+            "#,
+            from_block
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM chain_events WHERE block_number >= $1
+            "#,
+            from_block
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // Contents with no surviving on-chain events fall back to the draft status
+        sqlx::query!(
+            r#"
+            UPDATE contents c SET status = COALESCE(
+                (
+                    SELECT CASE e.event_type
+                        WHEN 'ContentPublished' THEN 'published'
+                        WHEN 'ContentChallenged' THEN 'challenged'
+                        WHEN 'ChallengeResolved' THEN 'resolved'
+                    END
+                    FROM chain_events e
+                    WHERE e.event_type IN ('ContentPublished', 'ContentChallenged', 'ChallengeResolved')
+                      AND (e.data->>'contentId')::BIGINT = c.content_id
+                    ORDER BY e.block_number DESC, e.log_index DESC
+                    LIMIT 1
+                ),
+                'pending'
+            )
+            WHERE c.content_id = ANY($1)
+            "#,
+            &affected
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // Publication was reorged out, so the row is a draft again
+        sqlx::query!(
+            r#"
+            UPDATE contents SET content_id = NULL
+            WHERE content_id = ANY($1) AND status = 'pending'
+            "#,
+            &affected
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM processed_blocks WHERE block_number >= $1
+            "#,
+            from_block
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            UPDATE block_cursors SET last_block = $1 - 1, updated_at = NOW()
+            WHERE last_block >= $1
+            "#,
+            from_block
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(users.into_iter().map(|r| r.address).collect())
+    }
+
+    // Dispute operations
+    pub async fn create_dispute(&self, dispute: Dispute) -> Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO disputes (
+                dispute_id, content_id, challenger_address, status, commit_deadline,
+                reveal_deadline, block_number, transaction_hash
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (dispute_id) DO NOTHING
+            "#,
+            dispute.dispute_id,
+            dispute.content_id,
+            dispute.challenger_address,
+            dispute.status,
+            dispute.commit_deadline,
+            dispute.reveal_deadline,
+            dispute.block_number,
+            dispute.transaction_hash
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_dispute(&self, dispute_id: i64) -> Result<Option<Dispute>> {
+        let dispute = sqlx::query_as!(
+            Dispute,
+            r#"
+            SELECT * FROM disputes WHERE dispute_id = $1
+            "#,
+            dispute_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(dispute)
+    }
+
+    /// Unresolved disputes whose reveal phase ended before `now`
+    pub async fn get_disputes_due_for_finalization(
+        &self,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<Dispute>> {
+        let disputes = sqlx::query_as!(
+            Dispute,
+            r#"
+            SELECT * FROM disputes
+            WHERE status = 'active' AND reveal_deadline < $1
+            ORDER BY reveal_deadline
+            "#,
+            now
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(disputes)
+    }
+
+    // Juror operations
+    pub async fn assign_jurors(
+        &self,
+        dispute_id: U256,
+        jurors: &[String],
+        block_number: u64,
+    ) -> Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO juror_assignments (dispute_id, juror_address, block_number)
+            SELECT $1, juror, $3 FROM UNNEST($2::VARCHAR[]) AS juror
+            ON CONFLICT (dispute_id, juror_address) DO NOTHING
+            "#,
+            i64::try_from(dispute_id)?,
+            jurors,
+            block_number as i64
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn record_juror_commit(
+        &self,
+        dispute_id: U256,
+        juror: String,
+        block_number: u64,
+    ) -> Result<()> {
+        sqlx::query!(
+            r#"
+            UPDATE juror_assignments SET committed = true, committed_block = $3
+            WHERE dispute_id = $1 AND juror_address = $2
+            "#,
+            i64::try_from(dispute_id)?,
+            juror,
+            block_number as i64
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn record_juror_reveal(
+        &self,
+        dispute_id: U256,
+        juror: String,
+        vote: bool,
+        block_number: u64,
+    ) -> Result<()> {
+        sqlx::query!(
+            r#"
+            UPDATE juror_assignments SET revealed = true, vote = $3, revealed_block = $4
+            WHERE dispute_id = $1 AND juror_address = $2
+            "#,
+            i64::try_from(dispute_id)?,
+            juror,
+            vote,
+            block_number as i64
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Every dispute `juror` was selected for, most recent first
+    pub async fn get_juror_assignments(&self, juror: &str) -> Result<Vec<JurorAssignment>> {
+        let assignments = sqlx::query_as!(
+            JurorAssignment,
+            r#"
+            SELECT j.dispute_id, j.juror_address, d.content_id, d.status AS dispute_status,
+                   d.commit_deadline, d.reveal_deadline, j.committed, j.revealed, j.vote,
+                   d.guilty,
+                   EXISTS(
+                       SELECT 1 FROM salt_escrows s
+                       WHERE s.dispute_id = j.dispute_id AND s.juror_address = j.juror_address
+                   ) AS "salt_escrowed!",
+                   j.created_at
+            FROM juror_assignments j
+            JOIN disputes d ON d.dispute_id = j.dispute_id
+            WHERE j.juror_address = $1
+            ORDER BY j.dispute_id DESC
+            "#,
+            juror
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(assignments)
+    }
+
+    pub async fn get_juror_assignment(
+        &self,
+        juror: &str,
+        dispute_id: i64,
+    ) -> Result<Option<JurorAssignment>> {
+        let assignment = sqlx::query_as!(
+            JurorAssignment,
+            r#"
+            SELECT j.dispute_id, j.juror_address, d.content_id, d.status AS dispute_status,
+                   d.commit_deadline, d.reveal_deadline, j.committed, j.revealed, j.vote,
+                   d.guilty,
+                   EXISTS(
+                       SELECT 1 FROM salt_escrows s
+                       WHERE s.dispute_id = j.dispute_id AND s.juror_address = j.juror_address
+                   ) AS "salt_escrowed!",
+                   j.created_at
+            FROM juror_assignments j
+            JOIN disputes d ON d.dispute_id = j.dispute_id
+            WHERE j.juror_address = $1 AND j.dispute_id = $2
+            "#,
+            juror,
+            dispute_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(assignment)
+    }
+
+    /// Stores or replaces a juror's escrowed vote and salt for a dispute
+    pub async fn upsert_salt_escrow(&self, escrow: SaltEscrow) -> Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO salt_escrows (dispute_id, juror_address, commitment, ciphertext, nonce)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (dispute_id, juror_address) DO UPDATE
+            SET commitment = EXCLUDED.commitment,
+                ciphertext = EXCLUDED.ciphertext,
+                nonce = EXCLUDED.nonce
+            "#,
+            escrow.dispute_id,
+            escrow.juror_address,
+            escrow.commitment,
+            escrow.ciphertext,
+            escrow.nonce
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_salt_escrow(
+        &self,
+        dispute_id: i64,
+        juror: &str,
+    ) -> Result<Option<SaltEscrow>> {
+        let escrow = sqlx::query_as!(
+            SaltEscrow,
+            r#"
+            SELECT * FROM salt_escrows WHERE dispute_id = $1 AND juror_address = $2
+            "#,
+            dispute_id,
+            juror
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(escrow)
+    }
+
+    // Reward epoch operations
+    pub async fn record_reward_epoch(
+        &self,
+        epoch_id: U256,
+        total_rewards: U256,
+        total_shares: U256,
+        transaction_hash: &str,
+        block_number: u64,
+    ) -> Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO reward_epochs (epoch_id, total_rewards, total_shares, transaction_hash, block_number)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (epoch_id) DO UPDATE
+            SET total_rewards = EXCLUDED.total_rewards,
+                total_shares = EXCLUDED.total_shares,
+                transaction_hash = EXCLUDED.transaction_hash,
+                block_number = EXCLUDED.block_number
+            "#,
+            i64::try_from(epoch_id)?,
+            BigDecimal::from_str(&total_rewards.to_string())?,
+            BigDecimal::from_str(&total_shares.to_string())?,
+            transaction_hash,
+            block_number as i64
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Ids of every epoch whose `EpochFinalized` event has been applied
+    pub async fn get_reward_epoch_ids(&self) -> Result<Vec<i64>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT epoch_id FROM reward_epochs ORDER BY epoch_id
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|r| r.epoch_id).collect())
+    }
+
+    // Relayer operations
+    /// Queues a call for the relayer. Returns `None` when a transaction with
+    /// the same `dedupe_key` is already queued, in flight or confirmed.
+    pub async fn enqueue_relayer_transaction(
+        &self,
+        kind: &str,
+        to_address: &str,
+        calldata: &str,
+        dedupe_key: Option<String>,
+    ) -> Result<Option<Uuid>> {
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO relayer_transactions (id, kind, to_address, calldata, dedupe_key)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (dedupe_key) WHERE status <> 'failed' DO NOTHING
+            RETURNING id
+            "#,
+            Uuid::new_v4(),
+            kind,
+            to_address,
+            calldata,
+            dedupe_key
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|r| r.id))
+    }
+
+    /// Moves the oldest queued transaction to `sending` and returns it
+    pub async fn claim_next_relayer_transaction(&self) -> Result<Option<RelayerTransaction>> {
+        let transaction = sqlx::query_as!(
+            RelayerTransaction,
+            r#"
+            UPDATE relayer_transactions SET status = 'sending'
+            WHERE id = (
+                SELECT id FROM relayer_transactions
+                WHERE status = 'queued'
+                ORDER BY created_at
+                FOR UPDATE SKIP LOCKED
+                LIMIT 1
+            )
+            RETURNING *
+            "#
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(transaction)
+    }
+
+    /// Serializes relayer nonce assignment across backend instances sharing a
+    /// signer; the lock is held until the returned transaction ends.
+    pub async fn lock_relayer_nonces(&self) -> Result<sqlx::Transaction<'static, sqlx::Postgres>> {
+        let mut lock = self.pool.begin().await?;
+        sqlx::query!("SELECT pg_advisory_xact_lock($1)", RELAYER_NONCE_LOCK)
+            .execute(&mut *lock)
+            .await?;
+
+        Ok(lock)
+    }
+
+    /// Reserves the next nonce for a transaction: the signer's pending nonce,
+    /// or one past the highest nonce still held by an unfinished transaction.
+    pub async fn reserve_relayer_nonce(&self, id: Uuid, pending_nonce: u64) -> Result<u64> {
+        let row = sqlx::query!(
+            r#"
+            UPDATE relayer_transactions
+            SET nonce = GREATEST($2, (
+                SELECT COALESCE(MAX(nonce) + 1, 0) FROM relayer_transactions
+                WHERE status IN ('queued', 'sending', 'submitted')
+            ))
+            WHERE id = $1
+            RETURNING nonce AS "nonce!"
+            "#,
+            id,
+            pending_nonce as i64
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.nonce as u64)
+    }
+
+    /// Hands back a nonce whose first broadcast never reached the network
+    pub async fn release_relayer_nonce(&self, id: Uuid) -> Result<()> {
+        sqlx::query!(
+            "UPDATE relayer_transactions SET nonce = NULL, tx_hash = NULL WHERE id = $1",
+            id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Records the signed transaction about to be broadcast. An earlier
+    /// attempt's hash is kept, since that broadcast may still be mined.
+    pub async fn record_relayer_signed(
+        &self,
+        id: Uuid,
+        gas_limit: u64,
+        max_fee_per_gas: i64,
+        max_priority_fee_per_gas: i64,
+        tx_hash: &str,
+    ) -> Result<()> {
+        sqlx::query!(
+            r#"
+            UPDATE relayer_transactions
+            SET replaced_tx_hashes = CASE
+                    WHEN tx_hash IS NULL THEN replaced_tx_hashes
+                    ELSE array_append(replaced_tx_hashes, tx_hash)
+                END,
+                gas_limit = $2, max_fee_per_gas = $3, max_priority_fee_per_gas = $4,
+                tx_hash = $5
+            WHERE id = $1
+            "#,
+            id,
+            gas_limit as i64,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            tx_hash
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn mark_relayer_submitted(&self, id: Uuid) -> Result<()> {
+        sqlx::query!(
+            r#"
+            UPDATE relayer_transactions
+            SET status = 'submitted', attempts = attempts + 1, last_error = NULL,
+                submitted_at = NOW()
+            WHERE id = $1
+            "#,
+            id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Records a fee-bumped rebroadcast under the same nonce, keeping the
+    /// previous hash since either transaction may be the one mined
+    pub async fn mark_relayer_replaced(
+        &self,
+        id: Uuid,
+        max_fee_per_gas: i64,
+        max_priority_fee_per_gas: i64,
+        tx_hash: &str,
+    ) -> Result<()> {
+        sqlx::query!(
+            r#"
+            UPDATE relayer_transactions
+            SET replaced_tx_hashes = array_append(replaced_tx_hashes, tx_hash),
+                max_fee_per_gas = $2, max_priority_fee_per_gas = $3, tx_hash = $4,
+                attempts = attempts + 1, last_error = NULL, submitted_at = NOW()
+            WHERE id = $1
+            "#,
+            id,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            tx_hash
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn mark_relayer_failed(&self, id: Uuid, error: &str) -> Result<()> {
+        sqlx::query!(
+            r#"
+            UPDATE relayer_transactions SET status = 'failed', last_error = $2
+            WHERE id = $1
+            "#,
+            id,
+            error
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Records a failed attempt, requeueing the transaction until `max_attempts` is reached.
+    /// Returns the nonce a transaction still held when it was given up on.
+    pub async fn mark_relayer_attempt_failed(
+        &self,
+        id: Uuid,
+        error: &str,
+        max_attempts: i32,
+    ) -> Result<Option<u64>> {
+        let row = sqlx::query!(
+            r#"
+            UPDATE relayer_transactions
+            SET attempts = attempts + 1,
+                last_error = $2,
+                status = CASE WHEN attempts + 1 >= $3 THEN 'failed' ELSE 'queued' END
+            WHERE id = $1
+            RETURNING CASE WHEN status = 'failed' THEN nonce END AS abandoned_nonce
+            "#,
+            id,
+            error,
+            max_attempts
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.abandoned_nonce.map(|nonce| nonce as u64))
+    }
+
+    pub async fn mark_relayer_mined(
+        &self,
+        id: Uuid,
+        success: bool,
+        block_number: Option<u64>,
+    ) -> Result<()> {
+        let status = if success { "confirmed" } else { "failed" };
+        let last_error = (!success).then_some("transaction reverted");
+
+        sqlx::query!(
+            r#"
+            UPDATE relayer_transactions
+            SET status = $2, block_number = $3, last_error = $4, confirmed_at = NOW()
+            WHERE id = $1
+            "#,
+            id,
+            status,
+            block_number.map(|b| b as i64),
+            last_error
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_submitted_relayer_transactions(&self) -> Result<Vec<RelayerTransaction>> {
+        let transactions = sqlx::query_as!(
+            RelayerTransaction,
+            r#"
+            SELECT * FROM relayer_transactions WHERE status = 'submitted'
+            ORDER BY nonce
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(transactions)
+    }
+
+    pub async fn get_relayer_transaction(&self, id: Uuid) -> Result<RelayerTransaction> {
+        let transaction = sqlx::query_as!(
+            RelayerTransaction,
+            "SELECT * FROM relayer_transactions WHERE id = $1",
+            id
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(transaction)
+    }
+
+    pub async fn get_sending_relayer_transactions(&self) -> Result<Vec<RelayerTransaction>> {
+        let transactions = sqlx::query_as!(
+            RelayerTransaction,
+            "SELECT * FROM relayer_transactions WHERE status = 'sending' ORDER BY nonce"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(transactions)
+    }
+
+    pub async fn requeue_relayer_transaction(&self, id: Uuid) -> Result<()> {
+        sqlx::query!(
+            "UPDATE relayer_transactions SET status = 'queued' WHERE id = $1",
+            id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn record_slashing(
+        &self,
+        user_address: String,
+        amount: U256,
+        reason: String,
     ) -> Result<()> {
+        // Store slashing event in chain_events table
         let event = ChainEvent {
             id: Uuid::new_v4(),
             block_number: 0,
+            block_hash: None,
             transaction_hash: "".to_string(),
-            event_type: "DisputeResolved".to_string(),
+            log_index: None,
+            event_type: "Slashed".to_string(),
             contract_address: "".to_string(),
             data: serde_json::json!({
-                "disputeId": dispute_id.to_string(),
-                "guilty": guilty,
-                "guiltyVotes": guilty_votes.to_string(),
-                "notGuiltyVotes": not_guilty_votes.to_string(),
+                "user": user_address,
+                "amount": amount.to_string(),
+                "reason": reason,
             }),
             processed: true,
             created_at: chrono::Utc::now(),
@@ -446,4 +1861,233 @@ impl Database {
         self.store_chain_event(event).await?;
         Ok(())
     }
+
+    pub async fn record_dispute_resolution(
+        &self,
+        dispute_id: U256,
+        guilty: bool,
+        guilty_votes: U256,
+        not_guilty_votes: U256,
+        block_number: u64,
+        transaction_hash: &str,
+    ) -> Result<()> {
+        sqlx::query!(
+            r#"
+            UPDATE disputes
+            SET status = 'resolved', guilty = $2, guilty_votes = $3, not_guilty_votes = $4,
+                resolved_block = $5, resolved_transaction_hash = $6, resolved_at = NOW()
+            WHERE dispute_id = $1
+            "#,
+            i64::try_from(dispute_id)?,
+            guilty,
+            i64::try_from(guilty_votes)?,
+            i64::try_from(not_guilty_votes)?,
+            block_number as i64,
+            transaction_hash
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Rebuilds the `platform_stats` rollup for every window in one
+    /// transaction, so readers never see a partial refresh.
+    pub async fn refresh_platform_stats(&self) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query!("DELETE FROM platform_stats")
+            .execute(&mut *tx)
+            .await?;
+
+        // Every content bond and challenge bond counts towards the bonded
+        // total, and its poster towards the window's active stakers while they
+        // still hold a stake. Both are dated on-chain: contents by publish
+        // time, challenges by the timestamp of the block that raised them.
+        sqlx::query!(
+            r#"
+            WITH windows(time_window, since) AS (
+                VALUES ('24h', NOW() - INTERVAL '24 hours'),
+                       ('7d', NOW() - INTERVAL '7 days'),
+                       ('30d', NOW() - INTERVAL '30 days'),
+                       ('all', '-infinity'::TIMESTAMPTZ)
+            ),
+            activity AS (
+                SELECT ct.community_id, ct.author_address AS address, ct.published_at AS at,
+                       ct.bond_amount, 1 AS contents, 0 AS challenges, 0 AS resolved, 0 AS guilty
+                FROM contents ct
+                WHERE ct.content_id IS NOT NULL
+                UNION ALL
+                SELECT ct.community_id, c.challenger_address, c.created_at,
+                       c.bond_amount, 0, 1, c.resolved::INT, (c.guilty IS TRUE)::INT
+                FROM challenges c
+                JOIN contents ct ON ct.id = c.content_id
+            )
+            INSERT INTO platform_stats (
+                time_window, is_total, community_id, total_contents, total_challenges,
+                total_resolved, total_guilty, total_bonded, active_stakers
+            )
+            SELECT w.time_window, GROUPING(a.community_id) = 1, a.community_id,
+                   SUM(a.contents), SUM(a.challenges), SUM(a.resolved), SUM(a.guilty),
+                   SUM(a.bond_amount),
+                   COUNT(DISTINCT a.address) FILTER (WHERE u.total_stake > 0)
+            FROM windows w
+            JOIN activity a ON a.at >= w.since
+            LEFT JOIN users u ON u.address = a.address
+            GROUP BY GROUPING SETS ((w.time_window), (w.time_window, a.community_id))
+            "#
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Cached platform totals and per-community rows for `time_window`
+    pub async fn get_platform_stats(&self, time_window: &str) -> Result<Vec<PlatformStats>> {
+        let stats = sqlx::query_as!(
+            PlatformStats,
+            r#"
+            SELECT time_window, is_total, community_id, total_contents, total_challenges,
+                   total_resolved, total_guilty, total_bonded, active_stakers, refreshed_at
+            FROM platform_stats
+            WHERE time_window = $1
+            ORDER BY is_total DESC, total_contents DESC, community_id
+            "#,
+            time_window
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(stats)
+    }
+
+    /// Inserts `community`, returning `false` when the slug is taken
+    pub async fn create_community(&self, community: &Community) -> Result<bool> {
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO communities (
+                slug, name, description, rules, icon_url, min_stake, allowed_content_types,
+                creator_address
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (slug) DO NOTHING
+            "#,
+            community.slug,
+            community.name,
+            community.description,
+            &community.rules,
+            community.icon_url,
+            community.min_stake,
+            &community.allowed_content_types,
+            community.creator_address
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn update_community(&self, community: &Community) -> Result<()> {
+        sqlx::query!(
+            r#"
+            UPDATE communities
+            SET name = $2, description = $3, rules = $4, icon_url = $5, min_stake = $6,
+                allowed_content_types = $7
+            WHERE slug = $1
+            "#,
+            community.slug,
+            community.name,
+            community.description,
+            &community.rules,
+            community.icon_url,
+            community.min_stake,
+            &community.allowed_content_types
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_community(&self, slug: &str) -> Result<Option<Community>> {
+        let community = sqlx::query_as!(
+            Community,
+            r#"
+            SELECT c.slug, c.name, c.description, c.rules, c.icon_url, c.min_stake,
+                   c.allowed_content_types, c.creator_address, c.created_at, c.updated_at,
+                   (SELECT COUNT(*) FROM community_members m WHERE m.community_slug = c.slug)
+                       AS "member_count!"
+            FROM communities c
+            WHERE c.slug = $1
+            "#,
+            slug
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(community)
+    }
+
+    /// Communities by member count, or only those `member` has joined
+    pub async fn list_communities(
+        &self,
+        member: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Community>> {
+        let communities = sqlx::query_as!(
+            Community,
+            r#"
+            SELECT c.slug, c.name, c.description, c.rules, c.icon_url, c.min_stake,
+                   c.allowed_content_types, c.creator_address, c.created_at, c.updated_at,
+                   (SELECT COUNT(*) FROM community_members m WHERE m.community_slug = c.slug)
+                       AS "member_count!"
+            FROM communities c
+            WHERE $1::VARCHAR IS NULL OR EXISTS (
+                SELECT 1 FROM community_members m
+                WHERE m.community_slug = c.slug AND m.member_address = $1
+            )
+            ORDER BY "member_count!" DESC, c.slug
+            LIMIT $2 OFFSET $3
+            "#,
+            member,
+            limit,
+            offset
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(communities)
+    }
+
+    pub async fn join_community(&self, slug: &str, member: &str) -> Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO community_members (community_slug, member_address)
+            VALUES ($1, $2)
+            ON CONFLICT DO NOTHING
+            "#,
+            slug,
+            member
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn leave_community(&self, slug: &str, member: &str) -> Result<()> {
+        sqlx::query!(
+            r#"
+            DELETE FROM community_members WHERE community_slug = $1 AND member_address = $2
+            "#,
+            slug,
+            member
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,184 @@
+//! Canonical content hashing. The hash committed on-chain at publish is
+//! recomputed from the stored fields to verify content, so every scheme ever
+//! used must stay available under its version number.
+
+use alloy::{
+    primitives::{keccak256, Address, B256},
+    sol,
+    sol_types::{eip712_domain, Eip712Domain, SolStruct},
+};
+use anyhow::Result;
+
+use crate::config::Config;
+use crate::models::Content;
+
+/// Version used for newly created content
+pub const CURRENT_HASH_VERSION: i16 = 3;
+
+sol! {
+    /// EIP-712 typed data for hash version 2. Absent optional fields are
+    /// encoded as empty strings.
+    struct MonadditContent {
+        string title;
+        string body;
+        string contentType;
+        string parentId;
+        string communityId;
+    }
+}
+
+mod v3 {
+    alloy::sol! {
+        /// EIP-712 typed data for hash version 3. Binding the author means a
+        /// hash seen in someone else's draft can't be published as one's own.
+        struct MonadditContent {
+            address author;
+            string title;
+            string body;
+            string contentType;
+            string parentId;
+            string communityId;
+        }
+    }
+}
+
+/// Hashes `content` with the scheme named by its `hash_version`
+pub fn content_hash(config: &Config, content: &Content) -> Result<B256> {
+    match content.hash_version {
+        // Legacy: no delimiter, so ("ab", "c") and ("a", "bc") collide
+        1 => Ok(keccak256(
+            [content.title.as_bytes(), content.body.as_bytes()].concat(),
+        )),
+        2 => {
+            let typed = MonadditContent {
+                title: content.title.clone(),
+                body: content.body.clone(),
+                contentType: content.content_type.clone(),
+                parentId: content
+                    .parent_id
+                    .map(|id| id.to_string())
+                    .unwrap_or_default(),
+                communityId: content.community_id.clone().unwrap_or_default(),
+            };
+
+            Ok(typed.eip712_signing_hash(&domain(config, "2")?))
+        }
+        3 => {
+            let typed = v3::MonadditContent {
+                author: content.author_address.parse()?,
+                title: content.title.clone(),
+                body: content.body.clone(),
+                contentType: content.content_type.clone(),
+                parentId: content
+                    .parent_id
+                    .map(|id| id.to_string())
+                    .unwrap_or_default(),
+                communityId: content.community_id.clone().unwrap_or_default(),
+            };
+
+            Ok(typed.eip712_signing_hash(&domain(config, "3")?))
+        }
+        version => Err(anyhow::anyhow!("Unknown content hash version {}", version)),
+    }
+}
+
+/// Bound to one chain and registry so hashes can't be replayed elsewhere
+fn domain(config: &Config, version: &'static str) -> Result<Eip712Domain> {
+    Ok(eip712_domain! {
+        name: "Monaddit",
+        version: version,
+        chain_id: config.chain_id,
+        verifying_contract: config.content_registry_address.parse::<Address>()?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::b256;
+    use sqlx::types::BigDecimal;
+
+    fn config() -> Config {
+        Config {
+            chain_id: 10143,
+            content_registry_address: "0x1111111111111111111111111111111111111111".to_string(),
+            ..Config::for_tests()
+        }
+    }
+
+    fn content(title: &str, body: &str, hash_version: i16) -> Content {
+        Content {
+            id: uuid::Uuid::nil(),
+            content_id: None,
+            author_address: "0x00000000000000000000000000000000000a11ce".to_string(),
+            content_hash: String::new(),
+            title: title.to_string(),
+            body: body.to_string(),
+            uri: None,
+            content_type: "post".to_string(),
+            parent_id: None,
+            community_id: Some("general".to_string()),
+            bond_amount: BigDecimal::from(0),
+            status: "pending".to_string(),
+            published_at: chrono::DateTime::UNIX_EPOCH,
+            lock_until: None,
+            created_at: chrono::DateTime::UNIX_EPOCH,
+            updated_at: chrono::DateTime::UNIX_EPOCH,
+            hash_version,
+        }
+    }
+
+    fn hash(content: &Content) -> B256 {
+        content_hash(&config(), content).unwrap()
+    }
+
+    #[test]
+    fn v1_matches_known_vector() {
+        // keccak256("abc")
+        assert_eq!(
+            hash(&content("ab", "c", 1)),
+            b256!("4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45")
+        );
+    }
+
+    #[test]
+    fn v2_matches_known_vector() {
+        assert_eq!(
+            hash(&content("ab", "c", 2)),
+            b256!("5ad6b27b99a7f4747f7d30d500f442a456fb9222c4acb77490b879bbbc15c713")
+        );
+    }
+
+    #[test]
+    fn v3_matches_known_vector() {
+        assert_eq!(
+            hash(&content("ab", "c", 3)),
+            b256!("714ffc9a236cbb8c19650786d64f0a879672158b921537e9a5ece600739fd334")
+        );
+    }
+
+    #[test]
+    fn field_boundaries_are_encoded() {
+        for version in [2, 3] {
+            assert_ne!(
+                hash(&content("ab", "c", version)),
+                hash(&content("a", "bc", version))
+            );
+        }
+        // The collision version 2 and later exist to prevent
+        assert_eq!(hash(&content("ab", "c", 1)), hash(&content("a", "bc", 1)));
+    }
+
+    #[test]
+    fn v3_binds_author() {
+        let mut other = content("ab", "c", 3);
+        other.author_address = "0x000000000000000000000000000000000000b0b0".to_string();
+
+        assert_ne!(hash(&content("ab", "c", 3)), hash(&other));
+    }
+
+    #[test]
+    fn unknown_version_is_rejected() {
+        assert!(content_hash(&config(), &content("ab", "c", 4)).is_err());
+    }
+}
@@ -2,8 +2,10 @@ mod api;
 mod chain;
 mod config;
 mod db;
+mod hashing;
 mod middleware;
 mod models;
+mod pagination;
 mod workers;
 
 use axum::{
@@ -18,7 +20,7 @@ use tracing::{error, info};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 use crate::{
-    chain::{ChainClient, EventListener},
+    chain::{ChainClient, EventListener, Relayer},
     config::Config,
     db::Database,
 };
@@ -27,6 +29,7 @@ use crate::{
 pub struct AppState {
     pub db: Database,
     pub chain_client: ChainClient,
+    pub relayer: Option<Relayer>,
     pub config: Config,
 }
 
@@ -59,18 +62,29 @@ async fn main() -> anyhow::Result<()> {
         .expect("Failed to initialize chain client");
     info!("Chain client initialized");
 
+    // Initialize relayer when a signing key is configured
+    let relayer = Relayer::new(config.clone(), db.clone())
+        .await
+        .expect("Failed to initialize relayer");
+    match &relayer {
+        Some(relayer) => info!("Relayer initialized for {:?}", relayer.address()),
+        None => info!("No WALLET_PRIVATE_KEY set, relayer disabled"),
+    }
+
     // Create app state
     let app_state = Arc::new(AppState {
         db: db.clone(),
         chain_client,
+        relayer: relayer.clone(),
         config: config.clone(),
     });
 
     // Start event listener in background
     let event_db = db.clone();
     let event_config = config.clone();
+    let event_chain_client = app_state.chain_client.clone();
     tokio::spawn(async move {
-        let listener = EventListener::new(event_config, event_db);
+        let listener = EventListener::new(event_config, event_db, event_chain_client);
         if let Err(e) = listener.start().await {
             error!("Event listener error: {}", e);
         }
@@ -84,11 +98,51 @@ async fn main() -> anyhow::Result<()> {
     });
     info!("Rewards worker started");
 
+    // Start dispute worker in background
+    let dispute_state = app_state.clone();
+    tokio::spawn(async move {
+        workers::disputes::start_dispute_worker(dispute_state).await;
+    });
+    info!("Dispute worker started");
+
+    // Start draft expiry worker in background
+    let draft_state = app_state.clone();
+    tokio::spawn(async move {
+        workers::drafts::start_draft_worker(draft_state).await;
+    });
+    info!("Draft expiry worker started");
+
+    // Start stats rollup worker in background
+    let stats_state = app_state.clone();
+    tokio::spawn(async move {
+        workers::stats::start_stats_worker(stats_state).await;
+    });
+    info!("Stats rollup worker started");
+
+    // Start feed ranking worker in background
+    let ranking_state = app_state.clone();
+    tokio::spawn(async move {
+        workers::rankings::start_ranking_worker(ranking_state).await;
+    });
+    info!("Feed ranking worker started");
+
+    // Start relayer worker in background
+    if let Some(relayer) = relayer {
+        let poll_interval_secs = config.relayer_poll_interval_secs;
+        tokio::spawn(async move {
+            workers::relayer::start_relayer_worker(relayer, poll_interval_secs).await;
+        });
+        info!("Relayer worker started");
+    }
+
     // Build router
     let app = Router::new()
         // Health check
         .route("/health", get(api::health_check))
         .route("/chain/status", get(api::chain_status))
+        // Auth endpoints
+        .route("/api/auth/nonce", get(api::auth::get_nonce))
+        .route("/api/auth/verify", post(api::auth::verify))
         // Content endpoints
         .route("/api/content", post(api::content::create_content))
         .route("/api/content/{id}", get(api::content::get_content))
@@ -97,7 +151,36 @@ async fn main() -> anyhow::Result<()> {
             "/api/content/hash/{hash}",
             get(api::content::get_content_by_hash),
         )
+        .route(
+            "/api/content/{id}/comments",
+            get(api::comment::get_comments),
+        )
         .route("/api/stats", get(api::content::get_stats))
+        .route("/api/search", get(api::search::search))
+        // Community endpoints
+        .route(
+            "/api/communities",
+            get(api::community::list_communities).post(api::community::create_community),
+        )
+        .route(
+            "/api/communities/{slug}",
+            get(api::community::get_community).post(api::community::update_community),
+        )
+        .route(
+            "/api/communities/{slug}/join",
+            post(api::community::join_community),
+        )
+        .route(
+            "/api/communities/{slug}/leave",
+            post(api::community::leave_community),
+        )
+        // Moderation endpoints
+        .route(
+            "/api/content/{id}/challenges",
+            get(api::challenge::list_content_challenges),
+        )
+        .route("/api/challenges", get(api::challenge::list_challenges))
+        .route("/api/disputes/{id}", get(api::challenge::get_dispute))
         // Scoring endpoints
         .route("/api/score", post(api::score::score_content))
         .route("/api/score/batch", post(api::score::batch_score))
@@ -107,6 +190,31 @@ async fn main() -> anyhow::Result<()> {
         // User endpoints
         .route("/api/user/{address}", get(api::user::get_user_profile))
         .route("/api/user/{address}", post(api::user::update_user_profile))
+        .route(
+            "/api/user/{address}/contents",
+            get(api::user::get_user_contents),
+        )
+        .route(
+            "/api/user/{address}/communities",
+            get(api::community::get_user_communities),
+        )
+        // Juror endpoints
+        .route(
+            "/api/juror/{address}/disputes",
+            get(api::juror::get_juror_disputes),
+        )
+        .route(
+            "/api/juror/{address}/disputes/{dispute_id}",
+            get(api::juror::get_juror_dispute),
+        )
+        .route(
+            "/api/juror/{address}/disputes/{dispute_id}/salt",
+            get(api::juror::get_escrowed_salt).post(api::juror::escrow_salt),
+        )
+        .route(
+            "/api/juror/commit-hash",
+            post(api::juror::compute_commit_hash),
+        )
         // Add state
         .with_state(app_state)
         // Add middleware
@@ -0,0 +1,76 @@
+use alloy::primitives::Address;
+use axum::{
+    extract::FromRequestParts,
+    http::{header::AUTHORIZATION, request::Parts, StatusCode},
+};
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::{config::Config, AppState};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Claims {
+    pub sub: String,
+    pub iat: i64,
+    pub exp: i64,
+}
+
+/// Wallet address proven through Sign-In with Ethereum, taken from the
+/// `Authorization: Bearer <jwt>` header.
+#[derive(Debug, Clone, Copy)]
+pub struct AuthUser(pub Address);
+
+impl FromRequestParts<Arc<AppState>> for AuthUser {
+    type Rejection = StatusCode;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<AppState>,
+    ) -> Result<Self, Self::Rejection> {
+        let token = parts
+            .headers
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .ok_or(StatusCode::UNAUTHORIZED)?;
+
+        let claims = decode::<Claims>(
+            token,
+            &DecodingKey::from_secret(state.config.api_secret_key.as_bytes()),
+            &Validation::default(),
+        )
+        .map_err(|_| StatusCode::UNAUTHORIZED)?
+        .claims;
+
+        let address = claims
+            .sub
+            .parse::<Address>()
+            .map_err(|_| StatusCode::UNAUTHORIZED)?;
+
+        Ok(AuthUser(address))
+    }
+}
+
+/// Issues a session token for `address`, returning the token and its expiry
+pub fn issue_token(
+    config: &Config,
+    address: Address,
+) -> anyhow::Result<(String, chrono::DateTime<chrono::Utc>)> {
+    let now = chrono::Utc::now();
+    let expires_at = now + chrono::Duration::seconds(config.jwt_ttl_secs as i64);
+
+    let claims = Claims {
+        sub: format!("{:?}", address),
+        iat: now.timestamp(),
+        exp: expires_at.timestamp(),
+    };
+
+    let token = encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(config.api_secret_key.as_bytes()),
+    )?;
+
+    Ok((token, expires_at))
+}
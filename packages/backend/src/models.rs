@@ -6,7 +6,7 @@ use uuid::Uuid;
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct Content {
     pub id: Uuid,
-    pub content_id: i64,
+    pub content_id: Option<i64>, // None until the draft is published on-chain
     pub author_address: String,
     pub content_hash: String,
     pub title: String,
@@ -21,8 +21,12 @@ pub struct Content {
     pub lock_until: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub hash_version: i16,
 }
 
+/// Accepted values of `Content::content_type`
+pub const CONTENT_TYPES: [&str; 2] = ["post", "comment"];
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ContentStatus {
     Published,
@@ -42,18 +46,82 @@ impl ToString for ContentStatus {
     }
 }
 
+/// Mirrors `ContentRegistry.ChallengeReason`; variants are in contract order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "challenge_reason", rename_all = "snake_case")]
+pub enum ChallengeReason {
+    Spam,
+    Harassment,
+    Misinformation,
+    IllegalContent,
+    Other,
+}
+
+impl ChallengeReason {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChallengeReason::Spam => "Spam",
+            ChallengeReason::Harassment => "Harassment",
+            ChallengeReason::Misinformation => "Misinformation",
+            ChallengeReason::IllegalContent => "Illegal content",
+            ChallengeReason::Other => "Other",
+        }
+    }
+}
+
+impl TryFrom<u8> for ChallengeReason {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(ChallengeReason::Spam),
+            1 => Ok(ChallengeReason::Harassment),
+            2 => Ok(ChallengeReason::Misinformation),
+            3 => Ok(ChallengeReason::IllegalContent),
+            4 => Ok(ChallengeReason::Other),
+            _ => Err(anyhow::anyhow!("Unknown challenge reason {}", value)),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct Challenge {
     pub id: Uuid,
     pub content_id: Uuid,
     pub challenger_address: String,
-    pub reason: String,
+    pub reason: ChallengeReason,
     pub evidence: Option<String>,
     pub bond_amount: BigDecimal,
     pub resolved: bool,
     pub guilty: Option<bool>,
     pub created_at: DateTime<Utc>,
     pub resolved_at: Option<DateTime<Utc>>,
+    pub block_number: Option<i64>,
+    pub resolved_block: Option<i64>,
+    pub slashed_amount: Option<BigDecimal>,
+}
+
+/// A challenge joined with the on-chain content ID and the dispute it opened
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ChallengeSummary {
+    pub id: Uuid,
+    pub content_id: Uuid,
+    pub chain_content_id: i64,
+    pub challenger_address: String,
+    pub reason: ChallengeReason,
+    pub evidence: Option<String>,
+    pub bond_amount: BigDecimal,
+    pub resolved: bool,
+    pub guilty: Option<bool>,
+    pub slashed_amount: Option<BigDecimal>,
+    pub created_at: DateTime<Utc>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub dispute_id: Option<i64>,
+    pub dispute_status: Option<String>,
+    pub reveal_deadline: Option<DateTime<Utc>>,
+    pub guilty_votes: Option<i64>,
+    pub not_guilty_votes: Option<i64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
@@ -74,6 +142,8 @@ pub struct Vote {
     pub content_id: Uuid,
     pub voter_address: String,
     pub vote_type: String,
+    /// Voter's reputation multiplier at vote time, in percent
+    pub weight: i32,
     pub created_at: DateTime<Utc>,
 }
 
@@ -91,7 +161,9 @@ pub struct ToxicityScore {
 pub struct ChainEvent {
     pub id: Uuid,
     pub block_number: i64,
+    pub block_hash: Option<String>,
     pub transaction_hash: String,
+    pub log_index: Option<i64>,
     pub event_type: String,
     pub contract_address: String,
     pub data: serde_json::Value,
@@ -100,7 +172,135 @@ pub struct ChainEvent {
     pub processed_at: Option<DateTime<Utc>>,
 }
 
+/// A `platform_stats` rollup row: platform-wide when `is_total`, otherwise
+/// for one community (`None` for content outside any community)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PlatformStats {
+    pub time_window: String,
+    pub is_total: bool,
+    pub community_id: Option<String>,
+    pub total_contents: i64,
+    pub total_challenges: i64,
+    pub total_resolved: i64,
+    pub total_guilty: i64,
+    pub total_bonded: BigDecimal,
+    pub active_stakers: i64,
+    pub refreshed_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct RelayerTransaction {
+    pub id: Uuid,
+    pub kind: String,
+    pub to_address: String,
+    pub calldata: String,
+    pub dedupe_key: Option<String>,
+    pub status: String,
+    pub nonce: Option<i64>,
+    pub gas_limit: Option<i64>,
+    pub tx_hash: Option<String>,
+    pub block_number: Option<i64>,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub submitted_at: Option<DateTime<Utc>>,
+    pub confirmed_at: Option<DateTime<Utc>>,
+    pub max_fee_per_gas: Option<i64>,
+    pub max_priority_fee_per_gas: Option<i64>,
+    pub replaced_tx_hashes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Dispute {
+    pub dispute_id: i64,
+    pub content_id: i64,
+    pub challenger_address: String,
+    pub status: String,
+    pub commit_deadline: DateTime<Utc>,
+    pub reveal_deadline: DateTime<Utc>,
+    pub guilty: Option<bool>,
+    pub guilty_votes: Option<i64>,
+    pub not_guilty_votes: Option<i64>,
+    pub block_number: i64,
+    pub transaction_hash: String,
+    pub resolved_block: Option<i64>,
+    pub resolved_transaction_hash: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+/// A juror's assignment joined with the dispute it belongs to
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct JurorAssignment {
+    pub dispute_id: i64,
+    pub juror_address: String,
+    pub content_id: i64,
+    pub dispute_status: String,
+    pub commit_deadline: DateTime<Utc>,
+    pub reveal_deadline: DateTime<Utc>,
+    pub committed: bool,
+    pub revealed: bool,
+    pub vote: Option<bool>,
+    pub guilty: Option<bool>,
+    pub salt_escrowed: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct SaltEscrow {
+    pub dispute_id: i64,
+    pub juror_address: String,
+    pub commitment: String,
+    pub ciphertext: String,
+    pub nonce: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
 // API Request/Response models
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Community {
+    pub slug: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub rules: Vec<String>,
+    pub icon_url: Option<String>,
+    /// Total stake an author needs to post, in wei
+    pub min_stake: BigDecimal,
+    pub allowed_content_types: Vec<String>,
+    pub creator_address: String,
+    pub member_count: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateCommunityRequest {
+    pub slug: String,
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub rules: Vec<String>,
+    pub icon_url: Option<String>,
+    /// In wei; defaults to 0
+    pub min_stake: Option<String>,
+    /// Defaults to post and comment
+    pub allowed_content_types: Option<Vec<String>>,
+}
+
+/// Fields left out keep their current value
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateCommunityRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub rules: Option<Vec<String>>,
+    pub icon_url: Option<String>,
+    pub min_stake: Option<String>,
+    pub allowed_content_types: Option<Vec<String>>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateContentRequest {
     pub title: String,
@@ -114,6 +314,7 @@ pub struct CreateContentRequest {
 pub struct CreateContentResponse {
     pub id: Uuid,
     pub content_hash: String,
+    pub hash_version: i16,
     pub estimated_gas: Option<String>,
 }
 
@@ -131,10 +332,147 @@ pub struct ScoreContentResponse {
     pub categories: Vec<String>,
 }
 
+/// Feed order for community listings
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FeedSort {
+    #[default]
+    New,
+    Hot,
+    Top,
+    Rising,
+    Controversial,
+}
+
+impl FeedSort {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FeedSort::New => "new",
+            FeedSort::Hot => "hot",
+            FeedSort::Top => "top",
+            FeedSort::Rising => "rising",
+            FeedSort::Controversial => "controversial",
+        }
+    }
+}
+
+/// Publication window for `top` and `controversial` feeds
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FeedWindow {
+    Day,
+    Week,
+    #[default]
+    All,
+}
+
+impl FeedWindow {
+    pub fn since(&self) -> Option<DateTime<Utc>> {
+        match self {
+            FeedWindow::Day => Some(Utc::now() - chrono::Duration::days(1)),
+            FeedWindow::Week => Some(Utc::now() - chrono::Duration::weeks(1)),
+            FeedWindow::All => None,
+        }
+    }
+}
+
+/// Sibling order for comment threads
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CommentSort {
+    #[default]
+    Top,
+    New,
+    Controversial,
+}
+
+impl CommentSort {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CommentSort::Top => "top",
+            CommentSort::New => "new",
+            CommentSort::Controversial => "controversial",
+        }
+    }
+}
+
+/// A comment in a thread with its vote tallies and moderation state
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct CommentRow {
+    pub id: Uuid,
+    pub content_id: i64,
+    pub parent_id: Uuid,
+    pub author_address: String,
+    pub body: String,
+    pub status: String,
+    pub published_at: DateTime<Utc>,
+    /// 1 for direct replies to the thread root
+    pub depth: i32,
+    /// Position among siblings in the requested order, encoded into cursors
+    pub sort_key: f64,
+    pub upvotes: i64,
+    pub downvotes: i64,
+    pub weighted_upvotes: i64,
+    pub weighted_downvotes: i64,
+    pub replies_count: i64,
+    /// A challenge against the comment was upheld
+    pub slashed: bool,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VoteType {
+    Upvote,
+    Downvote,
+}
+
+impl VoteType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VoteType::Upvote => "upvote",
+            VoteType::Downvote => "downvote",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VoteRequest {
     pub content_id: Uuid,
-    pub vote_type: String, // "upvote" or "downvote"
+    pub vote_type: VoteType,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateProfileRequest {
+    pub username: Option<String>,
+}
+
+/// A published post or comment matching a search, with highlighted excerpts
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct SearchResult {
+    pub id: Uuid,
+    pub content_id: i64,
+    pub author_address: String,
+    pub content_type: String,
+    pub parent_id: Option<Uuid>,
+    pub community_id: Option<String>,
+    pub status: String,
+    pub published_at: DateTime<Utc>,
+    pub title: String,
+    /// HTML-escaped title and body fragments with matches wrapped in `<mark>`;
+    /// safe to render as HTML
+    pub title_highlight: String,
+    pub snippet: String,
+    pub rank: f64,
+}
+
+/// Filters for `Database::search_contents`
+#[derive(Debug, Clone, Default)]
+pub struct SearchFilter {
+    pub community_id: Option<String>,
+    pub author_address: Option<String>,
+    pub status: Option<String>,
+    pub published_after: Option<DateTime<Utc>>,
+    pub published_before: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -142,6 +480,13 @@ pub struct ContentWithStats {
     pub content: Content,
     pub upvotes: i64,
     pub downvotes: i64,
+    /// Vote totals scaled by each voter's reputation multiplier, in
+    /// hundredths of a vote
+    pub weighted_upvotes: i64,
+    pub weighted_downvotes: i64,
     pub comments_count: i64,
     pub author: Option<User>,
+    /// Position in the requested feed order, encoded into cursors
+    #[serde(skip)]
+    pub sort_key: f64,
 }
@@ -0,0 +1,269 @@
+//! Keyset pagination. Listings are ordered by `(key, published_at, id)`
+//! descending, and a cursor records that position for the first or last row
+//! of a page. Cursors are signed so clients can't forge positions.
+
+use anyhow::Result;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use uuid::Uuid;
+
+use crate::config::Config;
+
+type HmacSha256 = Hmac<Sha256>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Cursor {
+    /// Listing the cursor was issued for, including its sort and filters
+    pub scope: String,
+    /// Sort key; 0 for listings ordered by time only
+    pub key: f64,
+    pub published_at: DateTime<Utc>,
+    pub id: Uuid,
+    /// Fetch the rows before this position instead of after it
+    pub backward: bool,
+}
+
+impl Cursor {
+    pub fn encode(&self, config: &Config) -> Result<String> {
+        let payload = serde_json::to_vec(self)?;
+        let signature = mac(config)?.chain_update(&payload).finalize().into_bytes();
+
+        Ok(format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(payload),
+            URL_SAFE_NO_PAD.encode(signature)
+        ))
+    }
+
+    /// Decodes a cursor, rejecting forged ones and those issued for another
+    /// listing
+    pub fn decode(token: &str, scope: &str, config: &Config) -> Result<Self> {
+        let (payload, signature) = token
+            .split_once('.')
+            .ok_or_else(|| anyhow::anyhow!("Malformed cursor"))?;
+        let payload = URL_SAFE_NO_PAD.decode(payload)?;
+        let signature = URL_SAFE_NO_PAD.decode(signature)?;
+
+        mac(config)?
+            .chain_update(&payload)
+            .verify_slice(&signature)
+            .map_err(|_| anyhow::anyhow!("Invalid cursor signature"))?;
+
+        let cursor: Self = serde_json::from_slice(&payload)?;
+        if cursor.scope != scope {
+            return Err(anyhow::anyhow!("Cursor belongs to another listing"));
+        }
+
+        Ok(cursor)
+    }
+}
+
+fn mac(config: &Config) -> Result<HmacSha256> {
+    Ok(HmacSha256::new_from_slice(
+        config.api_secret_key.as_bytes(),
+    )?)
+}
+
+#[derive(Debug, Serialize)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+    pub prev_cursor: Option<String>,
+}
+
+impl<T> Page<T> {
+    /// Builds a page from up to `limit + 1` rows fetched in the direction of
+    /// `request`; the extra row only signals that more rows follow.
+    pub fn new(
+        mut rows: Vec<T>,
+        limit: i64,
+        request: Option<&Cursor>,
+        scope: &str,
+        config: &Config,
+        position: impl Fn(&T) -> (f64, DateTime<Utc>, Uuid),
+    ) -> Result<Self> {
+        let backward = request.is_some_and(|cursor| cursor.backward);
+        let has_more = rows.len() as i64 > limit;
+        rows.truncate(limit.max(0) as usize);
+
+        // Backward pages are fetched nearest-first
+        let (more_before, more_after) = if backward {
+            rows.reverse();
+            (has_more, true)
+        } else {
+            (request.is_some(), has_more)
+        };
+
+        let cursor = |row: &T, backward: bool| {
+            let (key, published_at, id) = position(row);
+            Cursor {
+                scope: scope.to_string(),
+                key,
+                published_at,
+                id,
+                backward,
+            }
+            .encode(config)
+        };
+
+        let prev_cursor = match rows.first() {
+            Some(row) if more_before => Some(cursor(row, true)?),
+            _ => None,
+        };
+        let next_cursor = match rows.last() {
+            Some(row) if more_after => Some(cursor(row, false)?),
+            _ => None,
+        };
+
+        Ok(Self {
+            items: rows,
+            next_cursor,
+            prev_cursor,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCOPE: &str = "feed:general:hot:all";
+
+    fn row(n: u128) -> (f64, DateTime<Utc>, Uuid) {
+        (
+            100.0 - n as f64,
+            DateTime::from_timestamp(1_700_000_000 - n as i64, 0).unwrap(),
+            Uuid::from_u128(n),
+        )
+    }
+
+    fn page(rows: Vec<u128>, limit: i64, request: Option<&Cursor>) -> Page<u128> {
+        Page::new(rows, limit, request, SCOPE, &Config::for_tests(), |n| {
+            row(*n)
+        })
+        .unwrap()
+    }
+
+    fn decode(token: &str) -> Cursor {
+        Cursor::decode(token, SCOPE, &Config::for_tests()).unwrap()
+    }
+
+    #[test]
+    fn cursor_round_trips() {
+        let (key, published_at, id) = row(3);
+        let cursor = Cursor {
+            scope: SCOPE.to_string(),
+            key,
+            published_at,
+            id,
+            backward: true,
+        };
+
+        let decoded = decode(&cursor.encode(&Config::for_tests()).unwrap());
+
+        assert_eq!(decoded.scope, SCOPE);
+        assert_eq!((decoded.key, decoded.published_at, decoded.id), row(3));
+        assert!(decoded.backward);
+    }
+
+    #[test]
+    fn tampered_cursor_is_rejected() {
+        let config = Config::for_tests();
+        let token = page(vec![1, 2, 3], 2, None).next_cursor.unwrap();
+        let (payload, signature) = token.split_once('.').unwrap();
+
+        // Payload edited to point elsewhere, keeping the original signature
+        let mut cursor = decode(&token);
+        cursor.id = Uuid::from_u128(99);
+        let forged = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&cursor).unwrap());
+        assert!(Cursor::decode(&format!("{}.{}", forged, signature), SCOPE, &config).is_err());
+
+        // Signature from another key
+        let other = Config {
+            api_secret_key: "other".to_string(),
+            ..Config::for_tests()
+        };
+        assert!(Cursor::decode(&token, SCOPE, &other).is_err());
+
+        assert!(Cursor::decode(payload, SCOPE, &config).is_err());
+        assert!(Cursor::decode("not-a-cursor", SCOPE, &config).is_err());
+    }
+
+    #[test]
+    fn cursor_is_bound_to_its_scope() {
+        let token = page(vec![1, 2, 3], 2, None).next_cursor.unwrap();
+        let config = Config::for_tests();
+
+        assert!(Cursor::decode(&token, "feed:general:new:all", &config).is_err());
+        assert!(Cursor::decode(&token, "feed:other:hot:all", &config).is_err());
+    }
+
+    #[test]
+    fn first_page_links_forward_only() {
+        let first = page(vec![1, 2, 3], 2, None);
+
+        assert_eq!(first.items, vec![1, 2]);
+        assert!(first.prev_cursor.is_none());
+        let next = decode(&first.next_cursor.unwrap());
+        assert!(!next.backward);
+        assert_eq!(next.id, Uuid::from_u128(2));
+
+        let only = page(vec![1, 2], 2, None);
+        assert!(only.next_cursor.is_none() && only.prev_cursor.is_none());
+    }
+
+    #[test]
+    fn forward_page_links_both_ways() {
+        let (key, published_at, id) = row(2);
+        let request = Cursor {
+            scope: SCOPE.to_string(),
+            key,
+            published_at,
+            id,
+            backward: false,
+        };
+
+        let middle = page(vec![3, 4, 5], 2, Some(&request));
+        assert_eq!(middle.items, vec![3, 4]);
+        let prev = decode(&middle.prev_cursor.unwrap());
+        assert!(prev.backward);
+        assert_eq!(prev.id, Uuid::from_u128(3));
+        let next = decode(&middle.next_cursor.unwrap());
+        assert!(!next.backward);
+        assert_eq!(next.id, Uuid::from_u128(4));
+
+        let last = page(vec![3, 4], 2, Some(&request));
+        assert!(last.prev_cursor.is_some());
+        assert!(last.next_cursor.is_none());
+    }
+
+    #[test]
+    fn backward_page_is_reordered_and_links_both_ways() {
+        let (key, published_at, id) = row(5);
+        let request = Cursor {
+            scope: SCOPE.to_string(),
+            key,
+            published_at,
+            id,
+            backward: true,
+        };
+
+        // Backward rows arrive nearest-first
+        let middle = page(vec![4, 3, 2], 2, Some(&request));
+        assert_eq!(middle.items, vec![3, 4]);
+        let prev = decode(&middle.prev_cursor.unwrap());
+        assert!(prev.backward);
+        assert_eq!(prev.id, Uuid::from_u128(3));
+        let next = decode(&middle.next_cursor.unwrap());
+        assert!(!next.backward);
+        assert_eq!(next.id, Uuid::from_u128(4));
+
+        let first = page(vec![2, 1], 2, Some(&request));
+        assert_eq!(first.items, vec![1, 2]);
+        assert!(first.prev_cursor.is_none());
+        assert!(first.next_cursor.is_some());
+    }
+}
@@ -0,0 +1,65 @@
+use alloy::primitives::U256;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time;
+use tracing::{debug, error, info, warn};
+
+use crate::chain::RelayerCall;
+use crate::AppState;
+
+pub async fn start_dispute_worker(state: Arc<AppState>) {
+    info!("Starting dispute worker");
+
+    let mut interval = time::interval(Duration::from_secs(
+        state.config.dispute_poll_interval_secs.max(1),
+    ));
+
+    loop {
+        interval.tick().await;
+
+        if let Err(e) = finalize_due_disputes(&state).await {
+            error!("Error finalizing disputes: {}", e);
+        }
+    }
+}
+
+/// Queues `finalizeDispute` for every tracked dispute whose reveal phase has
+/// ended. Resolution is recorded by the listener from `DisputeResolved`.
+async fn finalize_due_disputes(state: &Arc<AppState>) -> anyhow::Result<()> {
+    // Compare against chain time, which is what the contract checks
+    let now = state.chain_client.get_latest_block_timestamp().await?;
+    let now = chrono::DateTime::from_timestamp(now as i64, 0).unwrap_or_default();
+
+    let disputes = state.db.get_disputes_due_for_finalization(now).await?;
+    if disputes.is_empty() {
+        return Ok(());
+    }
+
+    let Some(relayer) = &state.relayer else {
+        warn!(
+            "{} disputes are past their reveal deadline but no relayer is configured",
+            disputes.len()
+        );
+        return Ok(());
+    };
+
+    for dispute in disputes {
+        let dedupe_key = format!("ModerationGame.finalizeDispute:{}", dispute.dispute_id);
+        let call = RelayerCall::FinalizeDispute {
+            dispute_id: U256::from(dispute.dispute_id),
+        };
+
+        match relayer.enqueue(call, Some(dedupe_key)).await? {
+            Some(id) => info!(
+                "Queued finalization of dispute {} as {}",
+                dispute.dispute_id, id
+            ),
+            None => debug!(
+                "Finalization of dispute {} already queued",
+                dispute.dispute_id
+            ),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time;
+use tracing::{error, info};
+
+use crate::AppState;
+
+/// How often unpublished drafts are checked against `DRAFT_TTL_SECS`
+const SWEEP_INTERVAL_SECS: u64 = 600;
+
+pub async fn start_draft_worker(state: Arc<AppState>) {
+    info!("Starting draft expiry worker");
+
+    let mut interval = time::interval(Duration::from_secs(SWEEP_INTERVAL_SECS));
+
+    loop {
+        interval.tick().await;
+
+        let cutoff =
+            chrono::Utc::now() - chrono::Duration::seconds(state.config.draft_ttl_secs as i64);
+
+        match state.db.expire_drafts(cutoff).await {
+            Ok(0) => {}
+            Ok(expired) => info!("Expired {} unpublished drafts", expired),
+            Err(e) => error!("Error expiring drafts: {}", e),
+        }
+    }
+}
@@ -1 +1,6 @@
+pub mod disputes;
+pub mod drafts;
+pub mod rankings;
+pub mod relayer;
 pub mod rewards;
+pub mod stats;
@@ -0,0 +1,22 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time;
+use tracing::{error, info};
+
+use crate::AppState;
+
+pub async fn start_ranking_worker(state: Arc<AppState>) {
+    info!("Starting feed ranking worker");
+
+    let mut interval = time::interval(Duration::from_secs(
+        state.config.ranking_refresh_interval_secs.max(1),
+    ));
+
+    loop {
+        interval.tick().await;
+
+        if let Err(e) = state.db.refresh_content_rankings().await {
+            error!("Error refreshing content rankings: {}", e);
+        }
+    }
+}
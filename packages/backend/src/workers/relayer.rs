@@ -0,0 +1,35 @@
+use std::time::Duration;
+use tokio::time;
+use tracing::{error, info};
+
+use crate::chain::Relayer;
+
+pub async fn start_relayer_worker(relayer: Relayer, poll_interval_secs: u64) {
+    info!("Starting relayer worker for {:?}", relayer.address());
+
+    // A crash can leave a claimed transaction signed and possibly broadcast
+    if let Err(e) = relayer.recover_interrupted().await {
+        error!("Error recovering interrupted relayer transactions: {}", e);
+    }
+
+    let mut interval = time::interval(Duration::from_secs(poll_interval_secs.max(1)));
+
+    loop {
+        interval.tick().await;
+
+        if let Err(e) = relayer.track_receipts().await {
+            error!("Error tracking relayer receipts: {}", e);
+        }
+
+        loop {
+            match relayer.submit_next().await {
+                Ok(true) => continue,
+                Ok(false) => break,
+                Err(e) => {
+                    error!("Error submitting relayer transaction: {}", e);
+                    break;
+                }
+            }
+        }
+    }
+}
@@ -1,16 +1,19 @@
-use alloy::providers::Provider;
+use alloy::primitives::{Address, U256};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::time;
-use tracing::{error, info};
+use tracing::{debug, error, info, warn};
 
+use crate::chain::RelayerCall;
 use crate::AppState;
 
 pub async fn start_rewards_worker(state: Arc<AppState>) {
     info!("Starting rewards worker");
 
-    // Run every hour to check if epoch needs to be finalized
-    let mut interval = time::interval(Duration::from_secs(3600));
+    // Check periodically whether the current epoch needs to be finalized
+    let mut interval = time::interval(Duration::from_secs(
+        state.config.rewards_poll_interval_secs.max(1),
+    ));
 
     loop {
         interval.tick().await;
@@ -24,27 +27,65 @@ pub async fn start_rewards_worker(state: Arc<AppState>) {
 async fn process_rewards_epoch(state: &Arc<AppState>) -> anyhow::Result<()> {
     info!("Checking rewards epoch status");
 
-    // Check if it's time to finalize the epoch (7 days have passed)
-    // This would interact with the StakingRewards contract
+    // Read from the contract rather than local state so an epoch already
+    // checkpointed by another instance (or anyone else) is seen as not due
+    let schedule = state.chain_client.get_epoch_schedule().await?;
 
-    // Get current block timestamp from chain
-    let provider = state.chain_client.provider();
-    let block = provider.get_block_number().await?;
+    if !schedule.is_due() {
+        debug!(
+            "Epoch {} not due (ends at {}, accumulated {})",
+            schedule.current_epoch,
+            schedule.last_epoch_time + schedule.epoch_duration,
+            schedule.accumulated_rewards
+        );
+        return Ok(());
+    }
 
-    info!("Current block: {}", block);
+    let Some(relayer) = &state.relayer else {
+        warn!(
+            "Epoch {} is due but no relayer is configured to checkpoint it",
+            schedule.current_epoch
+        );
+        return Ok(());
+    };
 
-    // Check if we need to call checkpoint on StakingRewards contract
-    // This is simplified - in production you'd check the contract state
+    // Keyed by epoch so every instance sharing the database queues it once
+    let dedupe_key = format!("StakingRewards.checkpoint:{}", schedule.current_epoch);
+    match relayer
+        .enqueue(RelayerCall::CheckpointRewards, Some(dedupe_key))
+        .await?
+    {
+        Some(id) => info!(
+            "Queued checkpoint for epoch {} as {}",
+            schedule.current_epoch, id
+        ),
+        None => debug!(
+            "Checkpoint for epoch {} already queued",
+            schedule.current_epoch
+        ),
+    }
 
     Ok(())
 }
 
-pub async fn calculate_user_rewards(
-    _state: &Arc<AppState>,
-    _user_address: String,
-) -> anyhow::Result<u64> {
-    // Calculate rewards for a specific user
-    // This would query the chain and database
+/// Sums the unclaimed rewards of `user` over every finalized epoch
+pub async fn calculate_user_rewards(state: &AppState, user: Address) -> anyhow::Result<U256> {
+    let epoch_ids: Vec<U256> = state
+        .db
+        .get_reward_epoch_ids()
+        .await?
+        .into_iter()
+        .map(U256::from)
+        .collect();
+
+    let infos = state
+        .chain_client
+        .get_user_epoch_infos(user, &epoch_ids)
+        .await?;
 
-    Ok(0)
+    Ok(infos
+        .iter()
+        .filter(|info| !info.claimed)
+        .map(|info| info.reward)
+        .sum())
 }
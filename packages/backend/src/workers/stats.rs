@@ -0,0 +1,22 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time;
+use tracing::{error, info};
+
+use crate::AppState;
+
+pub async fn start_stats_worker(state: Arc<AppState>) {
+    info!("Starting stats rollup worker");
+
+    let mut interval = time::interval(Duration::from_secs(
+        state.config.stats_refresh_interval_secs.max(1),
+    ));
+
+    loop {
+        interval.tick().await;
+
+        if let Err(e) = state.db.refresh_platform_stats().await {
+            error!("Error refreshing platform stats: {}", e);
+        }
+    }
+}